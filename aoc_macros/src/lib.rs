@@ -0,0 +1,211 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, Ident, LitInt, LitStr, Token, braced};
+
+const DAYS: std::ops::RangeInclusive<u8> = 1..=25;
+
+/// Generates a test per example case of a day, reading `input/<YEAR>/<file>`
+/// (`YEAR` comes from the enclosing year module) and checking the listed parts
+/// against `DayNN` (zero-padded from the day number):
+///
+/// ```ignore
+/// test_solution!(11,
+///     example: "day11_test.txt" => { part_1: "5" },
+///     part_2_example: "day11_part2_test.txt" => { part_2: "2" },
+/// );
+/// ```
+///
+/// `test_solution!(1, "3", "6")` is shorthand for a single `example` case
+/// reading `day01_test.txt` and checking both parts.
+#[proc_macro]
+pub fn test_solution(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    syn::parse_macro_input!(input as TestSolution)
+        .expand()
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct TestSolution {
+    day: u8,
+    cases: Vec<Case>,
+}
+
+struct Case {
+    name: Ident,
+    file: LitStr,
+    expectations: Vec<(Ident, Expr)>,
+}
+
+struct Expectation {
+    part: Ident,
+    expected: Expr,
+}
+
+impl Parse for Expectation {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let part: Ident = input.parse()?;
+        if part != "part_1" && part != "part_2" {
+            return Err(syn::Error::new(
+                part.span(),
+                "expected `part_1` or `part_2`",
+            ));
+        }
+        input.parse::<Token![:]>()?;
+        Ok(Expectation {
+            part,
+            expected: input.parse()?,
+        })
+    }
+}
+
+impl Parse for Case {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let file = input.parse()?;
+        input.parse::<Token![=>]>()?;
+
+        let content;
+        braced!(content in input);
+        let expectations = Punctuated::<Expectation, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .map(|e| (e.part, e.expected))
+            .collect();
+
+        Ok(Case {
+            name,
+            file,
+            expectations,
+        })
+    }
+}
+
+impl Parse for TestSolution {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let day_lit: LitInt = input.parse()?;
+        let day = day_lit
+            .base10_parse::<u8>()
+            .ok()
+            .filter(|day| DAYS.contains(day))
+            .ok_or_else(|| {
+                syn::Error::new(
+                    day_lit.span(),
+                    format!(
+                        "day must be between {} and {}, found `{}`",
+                        DAYS.start(),
+                        DAYS.end(),
+                        day_lit
+                    ),
+                )
+            })?;
+        input.parse::<Token![,]>()?;
+
+        let cases = if input.peek(Ident) && input.peek2(Token![:]) {
+            Punctuated::<Case, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect()
+        } else {
+            let part_1: Expr = input.parse()?;
+            input.parse::<Token![,]>()?;
+            let part_2: Expr = input.parse()?;
+            input.parse::<Option<Token![,]>>()?;
+
+            vec![Case {
+                name: Ident::new("example", Span::call_site()),
+                file: LitStr::new(&format!("day{:02}_test.txt", day), day_lit.span()),
+                expectations: vec![
+                    (Ident::new("part_1", Span::call_site()), part_1),
+                    (Ident::new("part_2", Span::call_site()), part_2),
+                ],
+            }]
+        };
+
+        Ok(TestSolution { day, cases })
+    }
+}
+
+impl TestSolution {
+    fn expand(&self) -> syn::Result<TokenStream> {
+        let solution = format_ident!("Day{:02}", self.day);
+
+        let tests = self.cases.iter().map(|case| {
+            let test_name = format_ident!("test_day{:02}_{}", self.day, case.name);
+            let file = case.file.value();
+            let checks = case.expectations.iter().map(|(part, expected)| {
+                let label = part.to_string();
+                quote! {
+                    assert_eq!(solver.#part(), #expected, #label);
+                }
+            });
+
+            quote! {
+                #[test]
+                fn #test_name() {
+                    use crate::solution::Solution;
+
+                    let path = format!("input/{}/{}", super::YEAR, #file);
+                    let input = std::fs::read_to_string(path).unwrap();
+
+                    let solver = #solution::new(&input).unwrap();
+                    println!("{:?}", solver);
+
+                    #(#checks)*
+                }
+            }
+        });
+
+        Ok(quote! { #(#tests)* })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(tokens: TokenStream) -> syn::Result<String> {
+        syn::parse2::<TestSolution>(tokens)?
+            .expand()
+            .map(|tokens| tokens.to_string())
+    }
+
+    #[test]
+    fn pads_day_and_derives_names() {
+        let expanded = expand(quote!(3, "357", "3121910778619")).unwrap();
+
+        assert!(expanded.contains("fn test_day03_example"));
+        assert!(expanded.contains("Day03 :: new"));
+        assert!(expanded.contains("\"day03_test.txt\""));
+        assert!(expanded.contains("super :: YEAR"));
+    }
+
+    #[test]
+    fn one_test_per_case() {
+        let expanded = expand(quote!(25,
+            example: "day25_test.txt" => { part_1: "5" },
+            other: "day25_other_test.txt" => { part_2: "2" },
+        ))
+        .unwrap();
+
+        assert!(expanded.contains("fn test_day25_example"));
+        assert!(expanded.contains("fn test_day25_other"));
+        assert_eq!(expanded.matches("# [test]").count(), 2);
+    }
+
+    #[test]
+    fn rejects_out_of_range_days() {
+        for day in [quote!(0), quote!(26), quote!(300)] {
+            let err = expand(quote!(#day, "1", "2")).err().unwrap();
+            assert!(err.to_string().starts_with("day must be between 1 and 25"));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_parts() {
+        let err = expand(quote!(1, example: "day01_test.txt" => { part_3: "1" }))
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "expected `part_1` or `part_2`");
+    }
+}
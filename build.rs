@@ -0,0 +1,105 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// (day, module name, source file)
+type DayModule = (u8, String, PathBuf);
+
+// Scans `src/yYYYY/` for `dayNN.rs` files and generates the year modules and
+// the registry entries for them, so adding a new day only needs the file.
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let src_dir = manifest_dir.join("src");
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    let mut years: Vec<(u16, Vec<DayModule>)> = fs::read_dir(&src_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.unwrap().path();
+            let year = parse_year_dir(&path)?;
+            Some((year, find_days(&path)))
+        })
+        .collect();
+    years.sort();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+    for (year, days) in &years {
+        modules += &format!("mod y{} {{\n    pub const YEAR: u16 = {};\n", year, year);
+        for (day, module, path) in days {
+            modules += &format!(
+                "    #[path = {:?}]\n    pub mod {};\n",
+                path.display().to_string(),
+                module
+            );
+            entries += &format!(
+                "    crate::registry::RegisteredDay::of::<crate::y{}::{}::Day{:02}>(crate::y{}::YEAR, {}),\n",
+                year, module, day, year, day
+            );
+        }
+        modules += "}\n";
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("day_modules.rs"), modules).unwrap();
+    fs::write(
+        out_dir.join("registered_days.rs"),
+        format!("[\n{}]\n", entries),
+    )
+    .unwrap();
+}
+
+fn find_days(year_dir: &Path) -> Vec<DayModule> {
+    let mut days: Vec<DayModule> = fs::read_dir(year_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.unwrap().path();
+            let stem = path.file_stem()?.to_str()?.to_string();
+            let day = parse_day_module(&stem, &path)?;
+            Some((day, stem, path))
+        })
+        .collect();
+    days.sort();
+
+    for pair in days.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            panic!(
+                "day {} is defined twice: `{}` and `{}`",
+                pair[0].0,
+                pair[0].2.display(),
+                pair[1].2.display()
+            );
+        }
+    }
+    days
+}
+
+fn parse_year_dir(path: &Path) -> Option<u16> {
+    if !path.is_dir() {
+        return None;
+    }
+    let digits = path.file_name()?.to_str()?.strip_prefix('y')?;
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn parse_day_module(stem: &str, path: &Path) -> Option<u8> {
+    if path.extension()? != "rs" {
+        return None;
+    }
+    let digits = stem.strip_prefix("day")?;
+    if digits.len() != 2 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let day: u8 = digits.parse().ok()?;
+    if !(1..=25).contains(&day) {
+        panic!(
+            "`{}`: day must be between 1 and 25, found {}",
+            path.display(),
+            day
+        );
+    }
+    Some(day)
+}
@@ -0,0 +1,181 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::iter::Sum;
+
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(i128),
+    Text(String),
+    Unsolved,
+}
+
+impl Answer {
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Integer(value) => Some(*value as i128),
+            Answer::BigInteger(value) => Some(*value),
+            Answer::Text(_) | Answer::Unsolved => None,
+        }
+    }
+
+    /// The numeric value of a number, or of text that renders exactly like one.
+    fn numeric_value(&self) -> Option<i128> {
+        match self {
+            Answer::Text(text) => text
+                .parse()
+                .ok()
+                .filter(|value: &i128| value.to_string() == *text),
+            _ => self.as_i128(),
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Answer::Unsolved)
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::BigInteger(_) => "big_integer",
+            Answer::Text(_) => "text",
+            Answer::Unsolved => "unsolved",
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::BigInteger(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+// numbers compare by value whatever their width, everything else by its rendering
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            (Answer::Unsolved, _) | (_, Answer::Unsolved) => false,
+            _ => match (self.as_i128(), other.as_i128()) {
+                (Some(a), Some(b)) => a == b,
+                _ => self.to_string() == other.to_string(),
+            },
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.is_solved() && self.to_string().as_str() == *other
+    }
+}
+
+// consistent with `eq`: numbers, and text that reads as one, go by value and
+// before any other text, which goes by its rendering
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Unsolved, Answer::Unsolved) => Some(Ordering::Equal),
+            (Answer::Unsolved, _) | (_, Answer::Unsolved) => None,
+            _ => match (self.numeric_value(), other.numeric_value()) {
+                (Some(a), Some(b)) => Some(a.cmp(&b)),
+                (Some(_), None) => Some(Ordering::Less),
+                (None, Some(_)) => Some(Ordering::Greater),
+                (None, None) => Some(self.to_string().cmp(&other.to_string())),
+            },
+        }
+    }
+}
+
+impl Sum for Answer {
+    /// Adds up numeric answers; the sum is unsolved if any answer isn't a number.
+    fn sum<I: Iterator<Item = Answer>>(iter: I) -> Self {
+        iter.map(|answer| answer.as_i128())
+            .try_fold(0i128, |acc, value| acc.checked_add(value?))
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        i64::try_from(value).map_or(Answer::BigInteger(value), Answer::Integer)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::from(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_compare_by_value() {
+        assert_eq!(Answer::from(42u64), Answer::BigInteger(42));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+        assert!(Answer::from(7) < Answer::from(u64::MAX));
+        assert_eq!(Answer::from(3121910778619i64), "3121910778619");
+        assert_ne!(Answer::Unsolved, "unsolved");
+    }
+
+    #[test]
+    fn mixed_text_and_numbers_order_like_they_compare() {
+        let text = Answer::from("3".to_string());
+        assert_eq!(text, Answer::from(3));
+        assert_eq!(text.partial_cmp(&Answer::from(3)), Some(Ordering::Equal));
+        assert!(Answer::from("abc".to_string()) > Answer::from(3));
+        assert!(Answer::from("abc".to_string()) > Answer::from(i64::MAX));
+
+        // "10" sorts after 9 like the number it equals, not before it like the string
+        let ten = Answer::from("10".to_string());
+        assert_eq!(ten, Answer::from(10));
+        assert!(Answer::from(10) > Answer::from(9));
+        assert!(ten > Answer::from(9));
+        assert!(Answer::from("010".to_string()) > Answer::from(9));
+        assert_ne!(Answer::from("010".to_string()), Answer::from(10));
+        assert_eq!(Answer::Unsolved.partial_cmp(&Answer::from(3)), None);
+    }
+
+    #[test]
+    fn sums_numeric_answers() {
+        let total: Answer = [Answer::from(1), Answer::from(u64::MAX)].into_iter().sum();
+        assert_eq!(total, Answer::BigInteger(u64::MAX as i128 + 1));
+
+        let total: Answer = [Answer::from(1), Answer::from("x")].into_iter().sum();
+        assert_eq!(total, Answer::Unsolved);
+    }
+}
@@ -0,0 +1,301 @@
+use crate::runner::{RunError, default_input_path, find_day, read_input};
+use crate::timing::{format_duration, measure};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// z-score of a two-sided 95% confidence interval
+const Z_95: f64 = 1.96;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Estimate {
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub std_dev_ns: f64,
+    pub ci_low_ns: f64,
+    pub ci_high_ns: f64,
+    pub samples: usize,
+    pub outliers: usize,
+}
+
+impl Estimate {
+    /// Rejects samples outside Tukey's fences (1.5 IQR past the quartiles) and
+    /// estimates the mean of the rest with a 95% confidence interval.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_unstable_by(|a, b| a.total_cmp(b));
+
+        let q1 = quantile(&nanos, 0.25);
+        let q3 = quantile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let (low_fence, high_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        let kept: Vec<f64> = nanos
+            .iter()
+            .copied()
+            .filter(|&ns| ns >= low_fence && ns <= high_fence)
+            .collect();
+
+        let n = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / n;
+        let variance = if kept.len() > 1 {
+            kept.iter().map(|ns| (ns - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+        let std_dev = variance.sqrt();
+        let margin = Z_95 * std_dev / n.sqrt();
+
+        Estimate {
+            mean_ns: mean,
+            median_ns: quantile(&kept, 0.5),
+            std_dev_ns: std_dev,
+            ci_low_ns: mean - margin,
+            ci_high_ns: mean + margin,
+            samples: kept.len(),
+            outliers: nanos.len() - kept.len(),
+        }
+    }
+
+    fn compare(&self, previous: &Estimate) -> Change {
+        if self.ci_low_ns > previous.ci_high_ns {
+            Change::Regressed
+        } else if self.ci_high_ns < previous.ci_low_ns {
+            Change::Improved
+        } else {
+            Change::Unchanged
+        }
+    }
+}
+
+/// Linear interpolation between the closest ranks of sorted `values`.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let rank = q * (sorted.len() - 1) as f64;
+    let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[low] + (sorted[high] - sorted[low]) * (rank - low as f64)
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format_duration(Duration::from_nanos(nanos.max(0.0).round() as u64))
+}
+
+#[derive(Debug, PartialEq)]
+enum Change {
+    Improved,
+    Unchanged,
+    Regressed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchRecord {
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    pub year: u16,
+    pub day: u8,
+    pub warmup: usize,
+    pub iterations: usize,
+    pub parse: Estimate,
+    pub prepare: Estimate,
+    pub part_1: Estimate,
+    pub part_2: Estimate,
+}
+
+impl BenchRecord {
+    fn phases(&self) -> [(&'static str, &Estimate); 4] {
+        [
+            ("parse", &self.parse),
+            ("prepare", &self.prepare),
+            ("part 1", &self.part_1),
+            ("part 2", &self.part_2),
+        ]
+    }
+}
+
+fn sample(warmup: usize, iterations: usize, mut f: impl FnMut()) -> Estimate {
+    sample_with_setup(warmup, iterations, || (), |_| f())
+}
+
+/// Like `sample`, but each run only times `f` on a fresh value from `setup`.
+fn sample_with_setup<S>(
+    warmup: usize,
+    iterations: usize,
+    mut setup: impl FnMut() -> S,
+    mut f: impl FnMut(&S),
+) -> Estimate {
+    for _ in 0..warmup {
+        f(&setup());
+    }
+    let samples: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let value = setup();
+            measure(|| f(&value)).1
+        })
+        .collect();
+    Estimate::from_samples(&samples)
+}
+
+fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn history_error(path: &Path, message: impl ToString) -> RunError {
+    RunError::History {
+        path: path.to_path_buf(),
+        message: message.to_string(),
+    }
+}
+
+fn load_history(path: &Path) -> Result<Vec<BenchRecord>, RunError> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(path).map_err(|err| history_error(path, err))?;
+    serde_json::from_str(&content).map_err(|err| history_error(path, err))
+}
+
+fn save_history(path: &Path, history: &[BenchRecord]) -> Result<(), RunError> {
+    let content = serde_json::to_string_pretty(history).map_err(|err| history_error(path, err))?;
+    fs::write(path, content + "\n").map_err(|err| history_error(path, err))
+}
+
+pub fn run_bench(
+    year: u16,
+    day: u8,
+    warmup: usize,
+    iterations: usize,
+    history_path: &Path,
+    save: bool,
+) -> Result<(), RunError> {
+    let registered = find_day(year, day)?;
+    let path = default_input_path(year, day);
+    let input = read_input(&path)?;
+
+    let solution = registered
+        .build(&input)
+        .map_err(|err| RunError::parse(&path, &input, err))?;
+    solution.prepare();
+
+    let record = BenchRecord {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        commit: current_commit(),
+        year,
+        day,
+        warmup,
+        iterations,
+        parse: sample(warmup, iterations, || {
+            let _ = black_box(registered.build(black_box(&input)));
+        }),
+        // prepared work is cached, so each run needs a fresh solution
+        prepare: sample_with_setup(
+            warmup,
+            iterations,
+            || registered.build(&input),
+            |fresh| {
+                if let Ok(fresh) = fresh {
+                    fresh.prepare();
+                }
+            },
+        ),
+        part_1: sample(warmup, iterations, || {
+            black_box(solution.part_1());
+        }),
+        part_2: sample(warmup, iterations, || {
+            black_box(solution.part_2());
+        }),
+    };
+
+    println!(
+        "{} day {:02}: {} iterations after {} warmup runs",
+        year, day, iterations, warmup
+    );
+    for (name, estimate) in record.phases() {
+        println!(
+            "{:<7}: {} ± {} (95% CI {} .. {}, median {}, {} outliers rejected)",
+            name,
+            format_nanos(estimate.mean_ns),
+            format_nanos(estimate.ci_high_ns - estimate.mean_ns),
+            format_nanos(estimate.ci_low_ns),
+            format_nanos(estimate.ci_high_ns),
+            format_nanos(estimate.median_ns),
+            estimate.outliers
+        );
+    }
+
+    let mut history = load_history(history_path)?;
+
+    if let Some(previous) = history
+        .iter()
+        .rev()
+        .find(|r| r.year == year && r.day == day)
+    {
+        println!(
+            "compared with {}:",
+            previous.commit.as_deref().unwrap_or("an unknown commit")
+        );
+        for ((name, current), (_, before)) in record.phases().into_iter().zip(previous.phases()) {
+            let delta = (current.mean_ns - before.mean_ns) / before.mean_ns * 100.0;
+            let verdict = match current.compare(before) {
+                Change::Improved => "improved",
+                Change::Unchanged => "no significant change",
+                Change::Regressed => "REGRESSION",
+            };
+            println!("{:<7}: {:+.1}% ({})", name, delta, verdict);
+        }
+    }
+
+    if save {
+        history.push(record);
+        save_history(history_path, &history)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_outliers_before_estimating() {
+        let mut samples: Vec<Duration> = (0..20)
+            .map(|i| Duration::from_micros(100 + i % 3))
+            .collect();
+        samples.push(Duration::from_millis(50));
+
+        let estimate = Estimate::from_samples(&samples);
+
+        assert_eq!(estimate.outliers, 1);
+        assert_eq!(estimate.samples, 20);
+        assert!(estimate.ci_low_ns <= estimate.mean_ns && estimate.mean_ns <= estimate.ci_high_ns);
+        assert!(estimate.ci_high_ns < 102_000.0);
+    }
+
+    #[test]
+    fn only_non_overlapping_intervals_count_as_changes() {
+        let at = |low: f64, high: f64| Estimate {
+            mean_ns: (low + high) / 2.0,
+            median_ns: (low + high) / 2.0,
+            std_dev_ns: 0.0,
+            ci_low_ns: low,
+            ci_high_ns: high,
+            samples: 10,
+            outliers: 0,
+        };
+
+        assert_eq!(at(10.0, 20.0).compare(&at(15.0, 25.0)), Change::Unchanged);
+        assert_eq!(at(30.0, 40.0).compare(&at(15.0, 25.0)), Change::Regressed);
+        assert_eq!(at(1.0, 5.0).compare(&at(15.0, 25.0)), Change::Improved);
+    }
+}
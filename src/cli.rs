@@ -0,0 +1,107 @@
+use crate::output::Format;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+pub struct Cli {
+    /// Event year (defaults to `year` in `aoc.toml`, or 2025)
+    #[arg(short, long, global = true, value_parser = clap::value_parser!(u16).range(2015..))]
+    pub year: Option<u16>,
+
+    /// Worker threads for parallel work (`1` forces single-threaded runs)
+    #[cfg(feature = "parallel")]
+    #[arg(long, global = true, value_parser = clap::value_parser!(u16).range(1..))]
+    pub threads: Option<u16>,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run a single day against its puzzle input
+    Run {
+        #[arg(short, long)]
+        day: u8,
+
+        /// Only run the given part (both parts are run when omitted)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Input file to use instead of `input/YYYY/dayNN.txt`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+
+        /// Repeat each phase this many times and report min/median/max
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        repeat: u32,
+
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Also print how each part reached its answer (text output only)
+        #[arg(short, long)]
+        explain: bool,
+    },
+    /// Run every registered day and print a summary table
+    All {
+        /// Repeat each phase this many times and report the median
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        repeat: u32,
+
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// List the registered days of every year and their inputs
+    List,
+    /// Check every day's answers on the real inputs against the accepted answers
+    Verify {
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Download a day's puzzle input into `input/YYYY/dayNN.txt`
+    Fetch {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Create `src/yYYYY/dayNN.rs` from the template and empty input files
+    New {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Submit a day's computed answer for one part
+    Submit {
+        #[arg(short, long)]
+        day: u8,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Rerun a day's examples and real input whenever its source or inputs change
+    Watch {
+        #[arg(short, long)]
+        day: u8,
+    },
+    /// Benchmark a day and compare it against the stored history
+    Bench {
+        #[arg(short, long)]
+        day: u8,
+
+        /// Untimed runs of each phase before sampling
+        #[arg(short, long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Timed runs of each phase
+        #[arg(short = 'n', long, default_value_t = 30, value_parser = clap::value_parser!(u32).range(2..))]
+        iterations: u32,
+
+        /// JSON file the results are appended to
+        #[arg(long, default_value = "bench_history.json")]
+        history: PathBuf,
+
+        /// Compare against the history without recording this run
+        #[arg(long)]
+        no_save: bool,
+    },
+}
@@ -0,0 +1,107 @@
+use crate::config::Config;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+use ureq::Agent;
+
+const USER_AGENT: &str = "aoc-runner (+https://github.com/SousaTrashBin/AOC_2025)";
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Http(ureq::Error),
+    Status { status: u16, body: String },
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session token, set `session` in `aoc.toml` or the AOC_SESSION variable"
+            ),
+            ClientError::Http(err) => write!(f, "request failed: {}", err),
+            ClientError::Status { status, body } => {
+                write!(f, "server answered {}: {}", status, body.trim())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ureq::Error> for ClientError {
+    fn from(err: ureq::Error) -> Self {
+        ClientError::Http(err)
+    }
+}
+
+/// Minimal client for the puzzle site, authenticated with the session cookie.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, ClientError> {
+        let session = config.session.clone().ok_or(ClientError::MissingSession)?;
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Ok(Client {
+            agent,
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            session,
+        })
+    }
+
+    fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(format!("{}/input", self.day_url(year, day)))
+            .header("Cookie", self.cookie())
+            .header("User-Agent", USER_AGENT)
+            .call()?;
+
+        read_body(response)
+    }
+
+    /// Posts an answer and returns the page the site answers with.
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let level = part.to_string();
+        let response = self
+            .agent
+            .post(format!("{}/answer", self.day_url(year, day)))
+            .header("Cookie", self.cookie())
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", level.as_str()), ("answer", answer)])?;
+
+        read_body(response)
+    }
+}
+
+fn read_body(mut response: ureq::http::Response<ureq::Body>) -> Result<String, ClientError> {
+    let status = response.status().as_u16();
+    let body = response.body_mut().read_to_string()?;
+    if status != 200 {
+        return Err(ClientError::Status { status, body });
+    }
+    Ok(body)
+}
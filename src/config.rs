@@ -0,0 +1,55 @@
+use crate::runner::RunError;
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_YEAR: u16 = 2025;
+
+/// Settings for talking to the puzzle site, read from `aoc.toml` (kept out of
+/// git since it holds the session token). `AOC_SESSION` and `AOC_BASE_URL`
+/// override the file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub year: u16,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            year: DEFAULT_YEAR,
+        }
+    }
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self, RunError> {
+        let config_error = |message: String| RunError::Config {
+            path: path.to_path_buf(),
+            message,
+        };
+
+        let mut config: Config = if path.exists() {
+            let content = fs::read_to_string(path).map_err(|err| config_error(err.to_string()))?;
+            toml::from_str(&content).map_err(|err| config_error(err.to_string()))?
+        } else {
+            Config::default()
+        };
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+}
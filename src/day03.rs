@@ -1,61 +0,0 @@
-use crate::solution::Solution;
-use crate::test_solution;
-
-#[derive(Debug)]
-pub struct Bank {
-    batteries: Vec<i64>,
-}
-
-#[derive(Debug)]
-pub struct Day03 {
-    banks: Vec<Bank>,
-}
-
-impl Solution for Day03 {
-    fn new(input: &str) -> Self {
-        let banks = input
-            .lines()
-            .map(|line| line.chars().map(|char| char.to_digit(10).unwrap() as i64))
-            .map(|digits| digits.collect())
-            .map(|batteries| Bank { batteries })
-            .collect();
-        Day03 { banks }
-    }
-
-    fn part_1(&self) -> String {
-        self.find_max_output_joltage(2)
-    }
-
-    fn part_2(&self) -> String {
-        self.find_max_output_joltage(12)
-    }
-}
-
-impl Day03 {
-    fn find_max_output_joltage(&self, battery_count: usize) -> String {
-        self.banks
-            .iter()
-            .map(|bank| bank.find_bank_max_output_joltage(battery_count))
-            .sum::<i64>()
-            .to_string()
-    }
-}
-
-impl Bank {
-    fn find_bank_max_output_joltage(&self, battery_count: usize) -> i64 {
-        (0..battery_count)
-            .fold((0, 0), |(joltage, start_idx), batteries_left| {
-                let search_end = self.batteries.len() - (battery_count - 1 - batteries_left);
-                let (offset, &digit) = self.batteries[start_idx..search_end]
-                    .iter()
-                    .enumerate()
-                    // max by the joltage then the smallest index
-                    .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(&a.0)))
-                    .unwrap();
-                (joltage * 10 + digit, start_idx + offset + 1)
-            })
-            .0
-    }
-}
-
-test_solution!(3, "357", "3121910778619");
@@ -0,0 +1,88 @@
+use crate::client::Client;
+use crate::config::Config;
+use crate::runner::{RunError, default_input_path};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Downloads a day's input to `input/YYYY/dayNN.txt`. Inputs never change, so a
+/// day that already has a non-empty input file is never downloaded again.
+pub fn fetch_input(config: &Config, day: u8, root: &Path) -> Result<PathBuf, RunError> {
+    let path = root.join(default_input_path(config.year, day));
+    if fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+        return Err(RunError::AlreadyFetched(path));
+    }
+
+    let client = Client::new(config).map_err(RunError::Client)?;
+    let input = client
+        .fetch_input(config.year, day)
+        .map_err(RunError::Client)?;
+
+    let write_error = |source| RunError::Write {
+        path: path.clone(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(write_error)?;
+    }
+    // write then rename so an interrupted download never looks cached
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, input).map_err(write_error)?;
+    fs::rename(&partial, &path).map_err(write_error)?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClientError;
+    use crate::test_server::{TestServer, config, temp_dir};
+
+    #[test]
+    fn downloads_into_input_layout_once() {
+        let server = TestServer::start(vec![(200, "1,2\n3,4\n".to_string())]);
+        let root = temp_dir("fetch_once");
+
+        let path = fetch_input(&config(&server), 9, &root).unwrap();
+        assert_eq!(path, root.join("input/2025/day09.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1,2\n3,4\n");
+
+        let again = fetch_input(&config(&server), 9, &root);
+        assert!(matches!(again, Err(RunError::AlreadyFetched(_))));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2025/day/9/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+    }
+
+    #[test]
+    fn failed_download_leaves_no_file() {
+        let server = TestServer::start(vec![(400, "Puzzle inputs differ by user.".to_string())]);
+        let root = temp_dir("fetch_failed");
+
+        let result = fetch_input(&config(&server), 3, &root);
+        assert!(matches!(
+            result,
+            Err(RunError::Client(ClientError::Status { status: 400, .. }))
+        ));
+        assert!(!root.join("input/2025/day03.txt").exists());
+    }
+
+    #[test]
+    fn requires_a_session() {
+        let server = TestServer::start(vec![]);
+        let config = Config {
+            session: None,
+            ..config(&server)
+        };
+
+        let result = fetch_input(&config, 1, &temp_dir("fetch_no_session"));
+        assert!(matches!(
+            result,
+            Err(RunError::Client(ClientError::MissingSession))
+        ));
+        assert!(server.requests().is_empty());
+    }
+}
@@ -0,0 +1,295 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Point on a 2D plane where `y` grows downwards, as in puzzle inputs.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    #[allow(dead_code)]
+    pub const ORIGIN: Point2 = Point2::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    #[allow(dead_code)]
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    #[allow(dead_code)]
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    #[allow(dead_code)]
+    pub fn euclidean(self, other: Point2) -> f64 {
+        let (dx, dy) = ((self.x - other.x) as f64, (self.y - other.y) as f64);
+        dx.hypot(dy)
+    }
+
+    /// Rotates a quarter turn clockwise (as seen on screen) around the origin.
+    #[allow(dead_code)]
+    pub fn rotate_right(self) -> Self {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// Rotates a quarter turn counter-clockwise (as seen on screen) around the origin.
+    #[allow(dead_code)]
+    pub fn rotate_left(self) -> Self {
+        Point2::new(self.y, -self.x)
+    }
+
+    pub fn neighbours_4(self) -> impl Iterator<Item = Point2> {
+        Direction::ALL.into_iter().map(move |d| self + d.offset())
+    }
+
+    pub fn neighbours_8(self) -> impl Iterator<Item = Point2> {
+        Direction8::ALL.into_iter().map(move |d| self + d.offset())
+    }
+}
+
+impl Point3 {
+    #[allow(dead_code)]
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    #[allow(dead_code)]
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    #[allow(dead_code)]
+    pub fn chebyshev(self, other: Point3) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    /// Squared euclidean distance, exact and enough for ordering by distance.
+    pub fn euclidean_squared(self, other: Point3) -> i64 {
+        let (dx, dy, dz) = (self.x - other.x, self.y - other.y, self.z - other.z);
+        dx * dx + dy * dy + dz * dz
+    }
+
+    pub fn euclidean(self, other: Point3) -> f64 {
+        (self.euclidean_squared(other) as f64).sqrt()
+    }
+
+    /// The six points sharing a face with this one.
+    #[allow(dead_code)]
+    pub fn neighbours_6(self) -> impl Iterator<Item = Point3> {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |offset| self + offset)
+    }
+}
+
+macro_rules! impl_point_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, factor: i64) -> $point {
+                $point { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2 { x, y });
+impl_point_ops!(Point3 { x, y, z });
+
+/// Orthogonal directions, clockwise from `Up`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    #[allow(dead_code)]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    #[allow(dead_code)]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// Orthogonal and diagonal directions, clockwise from `North` (up).
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction8::North => Point2::new(0, -1),
+            Direction8::NorthEast => Point2::new(1, -1),
+            Direction8::East => Point2::new(1, 0),
+            Direction8::SouthEast => Point2::new(1, 1),
+            Direction8::South => Point2::new(0, 1),
+            Direction8::SouthWest => Point2::new(-1, 1),
+            Direction8::West => Point2::new(-1, 0),
+            Direction8::NorthWest => Point2::new(-1, -1),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    #[allow(dead_code)]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point2::new(1, 2), Point2::new(4, -2));
+
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.euclidean(b), 5.0);
+        assert_eq!(Point3::new(1, 2, 3).manhattan(Point3::ORIGIN), 6);
+        assert_eq!(Point3::new(2, 3, 6).euclidean(Point3::ORIGIN), 7.0);
+    }
+
+    #[test]
+    fn rotations_match_direction_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.offset().rotate_right(),
+                direction.turn_right().offset()
+            );
+            assert_eq!(
+                direction.offset().rotate_left(),
+                direction.turn_left().offset()
+            );
+            assert_eq!(-direction.offset(), direction.opposite().offset());
+        }
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+    }
+
+    #[test]
+    fn neighbours() {
+        let p = Point2::new(5, 5);
+
+        assert!(p.neighbours_4().all(|n| p.manhattan(n) == 1));
+        assert!(p.neighbours_8().all(|n| p.chebyshev(n) == 1));
+        assert_eq!(p.neighbours_8().count(), 8);
+        assert_eq!(Point3::ORIGIN.neighbours_6().count(), 6);
+    }
+}
@@ -0,0 +1,217 @@
+use crate::geometry::Point2;
+use crate::parse::ParseError;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// Dense row-major grid addressed by `Point2` (`x` is the column, `y` the row).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    #[allow(dead_code)]
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses one row per line, mapping every char with `f`. All rows must
+    /// have the same width.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        let mut lines = input.lines().peekable();
+        let width = lines.peek().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for line in lines {
+            let row_width = line.chars().count();
+            if row_width != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected a row of width {}, found {}", width, row_width),
+                ));
+            }
+            cells.extend(line.chars().map(&mut f));
+            height += 1;
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point2) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    fn index_of(&self, pos: Point2) -> Option<usize> {
+        let x = usize::try_from(pos.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(pos.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    fn position_of(&self, idx: usize) -> Point2 {
+        Point2::new((idx % self.width) as i64, (idx / self.width) as i64)
+    }
+
+    pub fn get(&self, pos: Point2) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Point2) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    #[allow(dead_code)]
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(if x < self.width { self.height } else { 0 })
+    }
+
+    #[allow(dead_code)]
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point2> + use<T> {
+        let width = self.width;
+        (0..self.cells.len())
+            .map(move |idx| Point2::new((idx % width) as i64, (idx / width) as i64))
+    }
+
+    #[allow(dead_code)]
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// In-bounds orthogonal neighbours of `pos`.
+    #[allow(dead_code)]
+    pub fn neighbours_4(&self, pos: Point2) -> impl Iterator<Item = Point2> {
+        pos.neighbours_4().filter(|&next| self.contains(next))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbours_8(&self, pos: Point2) -> impl Iterator<Item = Point2> {
+        pos.neighbours_8().filter(|&next| self.contains(next))
+    }
+
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point2> {
+        self.cells
+            .iter()
+            .position(&mut predicate)
+            .map(|idx| self.position_of(idx))
+    }
+
+    /// Renders the grid back to text, one line per row.
+    pub fn display_with(&self, mut f: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .take(self.height)
+            .map(|row| row.iter().map(&mut f).collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+impl Grid<char> {
+    pub fn find_char(&self, c: char) -> Option<Point2> {
+        self.find(|&cell| cell == c)
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_with(|&c| c))
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point2) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, pos: Point2) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "ab.\n.S#\n";
+
+    fn at(x: i64, y: i64) -> Point2 {
+        Point2::new(x, y)
+    }
+
+    #[test]
+    fn parses_and_displays_back() {
+        let grid = Grid::parse(INPUT, |c| c).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(at(2, 1)), Some(&'#'));
+        assert_eq!(grid.get(at(3, 0)), None);
+        assert_eq!(grid.get(at(-1, 0)), None);
+        assert_eq!(grid.find_char('S'), Some(at(1, 1)));
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = Grid::parse(INPUT, |c| c).unwrap();
+
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, ["ab.", ".S#"]);
+
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["a.", "bS", ".#"]);
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbours_4(at(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours_8(at(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours_8(at(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let err = Grid::parse("abc\nab\n", |c| c).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -1,86 +1,93 @@
-use crate::day11::Day11;
-use crate::solution::Solution;
-use std::fs;
-use std::time::Instant;
+use crate::cli::{Cli, Command};
+use aoc_macros::test_solution;
+use clap::Parser;
+use std::path::Path;
+use std::process::ExitCode;
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
+mod answer;
+mod bench;
+mod cli;
+mod client;
+mod config;
+mod fetch;
+mod geometry;
+mod grid;
+mod output;
+mod parallel;
+mod parse;
+mod registry;
+mod runner;
+mod scaffold;
 mod solution;
+mod submit;
+#[cfg(test)]
+mod test_server;
+mod timing;
+mod verify;
+mod watch;
 
-fn main() {
-    let input = fs::read_to_string("input/day11.txt").unwrap();
-    let day = Day11::new(&input);
+// `mod yYYYY { mod dayNN; }` for every `src/yYYYY/dayNN.rs`, generated by `build.rs`
+include!(concat!(env!("OUT_DIR"), "/day_modules.rs"));
 
-    let measure = |f: &dyn Fn() -> String| {
-        let start = Instant::now();
-        let result = f();
-        let elapsed = start.elapsed().as_millis();
-        (result, elapsed)
-    };
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    #[cfg(feature = "parallel")]
+    if let Some(threads) = cli.threads {
+        parallel::set_threads(threads as usize);
+    }
 
-    let (p1, t1) = measure(&|| day.part_1());
-    println!("part 1: {} ({} ms)", p1, t1);
+    let result = config::Config::load(Path::new(config::CONFIG_PATH)).and_then(|mut config| {
+        if let Some(year) = cli.year {
+            config.year = year;
+        }
+        run(cli.command, &config)
+    });
 
-    let (p2, t2) = measure(&|| day.part_2());
-    println!("part 2: {} ({} ms)", p2, t2);
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
 }
 
-#[macro_export]
-macro_rules! test_solution {
-    (1, $e1:expr, $e2:expr) => {
-        $crate::test_solution!(01, $e1, $e2);
-    };
-    (2, $e1:expr, $e2:expr) => {
-        $crate::test_solution!(02, $e1, $e2);
-    };
-    (3, $e1:expr, $e2:expr) => {
-        $crate::test_solution!(03, $e1, $e2);
-    };
-    (4, $e1:expr, $e2:expr) => {
-        $crate::test_solution!(04, $e1, $e2);
-    };
-    (5, $e1:expr, $e2:expr) => {
-        $crate::test_solution!(05, $e1, $e2);
-    };
-    (6, $e1:expr, $e2:expr) => {
-        $crate::test_solution!(06, $e1, $e2);
-    };
-    (7, $e1:expr, $e2:expr) => {
-        $crate::test_solution!(07, $e1, $e2);
-    };
-    (8, $e1:expr, $e2:expr) => {
-        $crate::test_solution!(08, $e1, $e2);
-    };
-    (9, $e1:expr, $e2:expr) => {
-        $crate::test_solution!(09, $e1, $e2);
-    };
-
-    ($day:literal, $expected1:expr, $expected2:expr) => {
-        paste::paste! {
-            #[test]
-            fn [<test_day$day>]() {
-                use crate::solution::Solution;
-                use std::fs;
-
-                let input = fs::read_to_string(
-                    concat!("input/day", stringify!($day), "_test.txt")
-                ).unwrap();
+fn run(command: Command, config: &config::Config) -> Result<(), runner::RunError> {
+    let year = config.year;
 
-                let solver = [<Day$day>]::new(&input);
-                println!("{:?}", solver);
-
-                assert_eq!(solver.part_1(), $expected1);
-                assert_eq!(solver.part_2(), $expected2);
-            }
+    match command {
+        Command::Run {
+            day,
+            part,
+            input,
+            repeat,
+            format,
+            explain,
+        } => runner::run_day(year, day, part, input, repeat as usize, format, explain),
+        Command::All { repeat, format } => {
+            runner::run_all(year, repeat as usize, format);
+            Ok(())
         }
-    };
+        Command::List => {
+            runner::list_days();
+            Ok(())
+        }
+        Command::Verify { answers } => verify::verify_all(year, &answers),
+        Command::Fetch { day } => fetch::fetch_input(config, day, Path::new("."))
+            .map(|path| println!("saved {}", path.display())),
+        Command::New { day } => scaffold::new_day(year, day, Path::new(".")).map(|created| {
+            for path in created {
+                println!("created {}", path.display());
+            }
+        }),
+        Command::Submit { day, part } => submit::run_submit(config, day, part),
+        Command::Watch { day } => runner::find_day(year, day).and_then(|_| watch::watch(year, day)),
+        Command::Bench {
+            day,
+            warmup,
+            iterations,
+            history,
+            no_save,
+        } => bench::run_bench(year, day, warmup, iterations as usize, &history, !no_save),
+    }
 }
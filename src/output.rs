@@ -0,0 +1,205 @@
+use crate::runner::{DayReport, RunError};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+/// One part of one day, as emitted by the machine-readable formats.
+#[derive(Debug, Serialize)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub success: bool,
+    pub answer: Option<String>,
+    pub kind: Option<&'static str>,
+    pub parse_ns: Option<u64>,
+    pub prepare_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub input_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+const CSV_HEADER: &str =
+    "year,day,part,success,answer,kind,parse_ns,prepare_ns,solve_ns,input_hash,error";
+
+/// 64-bit FNV-1a of the input, so results can be matched to the exact input
+/// they came from. Unlike `DefaultHasher` it is stable across toolchains.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+pub fn entries(report: &DayReport) -> Vec<Entry> {
+    [(1, &report.part_1), (2, &report.part_2)]
+        .into_iter()
+        .filter_map(|(part, result)| {
+            let (answer, stats) = result.as_ref()?;
+            Some(Entry {
+                year: report.year,
+                day: report.day,
+                part,
+                success: true,
+                answer: answer.is_solved().then(|| answer.to_string()),
+                kind: Some(answer.kind()),
+                parse_ns: Some(report.parse.median.as_nanos() as u64),
+                prepare_ns: Some(report.prepare.median.as_nanos() as u64),
+                solve_ns: Some(stats.median.as_nanos() as u64),
+                input_hash: Some(report.input_hash.clone()),
+                error: None,
+            })
+        })
+        .collect()
+}
+
+pub fn error_entries(year: u16, day: u8, part: Option<u8>, err: &RunError) -> Vec<Entry> {
+    let parts = part.map_or(vec![1, 2], |part| vec![part]);
+    parts
+        .into_iter()
+        .map(|part| Entry {
+            year,
+            day,
+            part,
+            success: false,
+            answer: None,
+            kind: None,
+            parse_ns: None,
+            prepare_ns: None,
+            solve_ns: None,
+            input_hash: None,
+            error: Some(error_message(err)),
+        })
+        .collect()
+}
+
+/// Parse errors leave out the source excerpt text output shows, keeping
+/// them on one line.
+fn error_message(err: &RunError) -> String {
+    match err {
+        RunError::Parse { error, .. } => error.to_string(),
+        _ => err.to_string(),
+    }
+}
+
+/// Renders entries as JSON or CSV; text output is printed by the runner itself.
+pub fn render(format: Format, entries: &[Entry]) -> String {
+    match format {
+        Format::Text => unreachable!("text output is not rendered from entries"),
+        Format::Json => serde_json::to_string_pretty(entries).unwrap() + "\n",
+        Format::Csv => {
+            let mut csv = format!("{}\n", CSV_HEADER);
+            for entry in entries {
+                let fields = [
+                    entry.year.to_string(),
+                    entry.day.to_string(),
+                    entry.part.to_string(),
+                    entry.success.to_string(),
+                    entry.answer.clone().unwrap_or_default(),
+                    entry.kind.unwrap_or_default().to_string(),
+                    entry.parse_ns.map(|ns| ns.to_string()).unwrap_or_default(),
+                    entry
+                        .prepare_ns
+                        .map(|ns| ns.to_string())
+                        .unwrap_or_default(),
+                    entry.solve_ns.map(|ns| ns.to_string()).unwrap_or_default(),
+                    entry.input_hash.clone().unwrap_or_default(),
+                    entry.error.clone().unwrap_or_default(),
+                ];
+                let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+                csv += &row.join(",");
+                csv.push('\n');
+            }
+            csv
+        }
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::parse::ParseError;
+    use crate::timing::Stats;
+    use std::path::Path;
+    use std::time::Duration;
+
+    fn report() -> DayReport {
+        let stats = |micros| Stats::from_samples(vec![Duration::from_micros(micros)]);
+        DayReport {
+            year: 2025,
+            day: 3,
+            input_hash: input_hash("987654321111111\n"),
+            parse: stats(5),
+            prepare: stats(2),
+            part_1: Some((Answer::from(357), stats(20))),
+            part_2: Some((Answer::Unsolved, stats(1))),
+            explanations: vec![],
+        }
+    }
+
+    #[test]
+    fn hashes_are_stable() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn json_has_one_entry_per_part() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(Format::Json, &entries(&report()))).unwrap();
+
+        assert_eq!(json[0]["part"], 1);
+        assert_eq!(json[0]["answer"], "357");
+        assert_eq!(json[0]["kind"], "integer");
+        assert_eq!(json[0]["parse_ns"], 5000);
+        assert_eq!(json[0]["prepare_ns"], 2000);
+        assert_eq!(json[0]["solve_ns"], 20000);
+        assert_eq!(json[1]["answer"], serde_json::Value::Null);
+        assert_eq!(json[1]["kind"], "unsolved");
+        assert_eq!(json[1]["success"], true);
+    }
+
+    #[test]
+    fn csv_quotes_errors() {
+        let err = RunError::SubmitRefused("a, \"b\"\nc".to_string());
+        let csv = render(Format::Csv, &error_entries(2025, 7, Some(2), &err));
+
+        assert_eq!(
+            csv,
+            format!(
+                "{}\n2025,7,2,false,,,,,,,\"not submitting: a, \"\"b\"\"\nc\"\n",
+                CSV_HEADER
+            )
+        );
+    }
+
+    #[test]
+    fn parse_errors_stay_on_one_line() {
+        let input = "12\n3x\n";
+        let error = ParseError::at(input, &input[4..5], "expected a digit").with_day(3);
+        let err = RunError::parse(Path::new("input/2025/day03.txt"), input, error);
+        let csv = render(Format::Csv, &error_entries(2025, 3, Some(1), &err));
+
+        assert_eq!(
+            csv,
+            format!(
+                "{}\n2025,3,1,false,,,,,,,\"day 03, line 2, column 2: expected a digit (found `x`)\"\n",
+                CSV_HEADER
+            )
+        );
+    }
+}
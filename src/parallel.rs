@@ -0,0 +1,38 @@
+//! Per-item parallelism for solutions and the runner. With the `parallel`
+//! feature the work is spread over rayon's thread pool, otherwise it runs
+//! serially; results always come back in input order.
+
+/// Applies `f` to every item, returning the results in the order of `items`.
+#[cfg(feature = "parallel")]
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R> {
+    use rayon::prelude::*;
+    items.par_iter().map(f).collect()
+}
+
+/// Applies `f` to every item, returning the results in the order of `items`.
+#[cfg(not(feature = "parallel"))]
+pub fn map<T, R>(items: &[T], f: impl Fn(&T) -> R) -> Vec<R> {
+    items.iter().map(f).collect()
+}
+
+/// Sizes the global thread pool; `1` makes everything run single-threaded,
+/// which keeps timings comparable when benchmarking.
+#[cfg(feature = "parallel")]
+pub fn set_threads(threads: usize) {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .expect("the thread pool is only configured once, at startup");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_input_order() {
+        let items: Vec<u64> = (0..1000).collect();
+        let squares = map(&items, |n| n * n);
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+    }
+}
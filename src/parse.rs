@@ -0,0 +1,102 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    /// 1-based line of the offending text
+    pub line: usize,
+    /// 1-based column (in chars) of the offending text
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Builds an error pointing at `fragment`, which must be a slice of `input`
+    /// (e.g. a line from `input.lines()` or a part of one).
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+        ParseError {
+            day: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: fragment.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error for something that should have been in `input` but wasn't.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    pub fn with_day(self, day: u8) -> Self {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {:02}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " (found `{}`)", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `field` (a slice of `input`), reporting failures as "invalid <what>".
+pub fn parse_field<T>(input: &str, field: &str, what: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    field
+        .parse()
+        .map_err(|err| ParseError::at(input, field, format!("invalid {}: {}", what, err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_fragment_in_input() {
+        let input = "L10\nR5\nLx3\n";
+        let line = input.lines().nth(2).unwrap();
+        let err = parse_field::<i32>(input, &line[1..], "distance").unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 2));
+        assert_eq!(err.text, "x3");
+        assert_eq!(
+            err.with_day(1).to_string(),
+            "day 01, line 3, column 2: invalid distance: invalid digit found in string (found `x3`)"
+        );
+    }
+
+    #[test]
+    fn end_of_input_points_past_last_line() {
+        let err = ParseError::at_end("a\nbc", "missing section");
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
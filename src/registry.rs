@@ -0,0 +1,58 @@
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+pub struct RegisteredDay {
+    pub year: u16,
+    pub day: u8,
+    constructor: fn(&str) -> Result<Box<dyn Solution>, ParseError>,
+}
+
+impl RegisteredDay {
+    const fn of<S: Solution + 'static>(year: u16, day: u8) -> Self {
+        RegisteredDay {
+            year,
+            day,
+            constructor: construct::<S>,
+        }
+    }
+
+    pub fn build(&self, input: &str) -> Result<Box<dyn Solution>, ParseError> {
+        (self.constructor)(input).map_err(|err| err.with_day(self.day))
+    }
+}
+
+fn construct<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solution>, ParseError> {
+    Ok(Box::new(S::new(input)?))
+}
+
+// one entry per `src/yYYYY/dayNN.rs`, generated by `build.rs`
+pub const DAYS: &[RegisteredDay] = &include!(concat!(env!("OUT_DIR"), "/registered_days.rs"));
+
+const _: () = assert!(
+    has_unique_days(DAYS),
+    "two solutions are registered for the same year and day"
+);
+
+const fn has_unique_days(days: &[RegisteredDay]) -> bool {
+    let mut i = 0;
+    while i < days.len() {
+        let mut j = i + 1;
+        while j < days.len() {
+            if days[i].year == days[j].year && days[i].day == days[j].day {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static RegisteredDay> {
+    days(year).find(|registered| registered.day == day)
+}
+
+pub fn days(year: u16) -> impl Iterator<Item = &'static RegisteredDay> {
+    DAYS.iter()
+        .filter(move |registered| registered.year == year)
+}
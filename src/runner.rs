@@ -0,0 +1,433 @@
+use crate::answer::Answer;
+use crate::client::ClientError;
+use crate::output::{self, Format};
+use crate::parallel;
+use crate::parse::ParseError;
+use crate::registry::{self, RegisteredDay};
+use crate::timing::{Stats, format_duration, measure, measure_repeated};
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+#[derive(Debug)]
+pub enum RunError {
+    UnknownDay {
+        year: u16,
+        day: u8,
+    },
+    MissingInput {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        source_line: String,
+        error: ParseError,
+    },
+    History {
+        path: PathBuf,
+        message: String,
+    },
+    Answers {
+        path: PathBuf,
+        message: String,
+    },
+    VerificationFailed(usize),
+    Config {
+        path: PathBuf,
+        message: String,
+    },
+    Client(ClientError),
+    AlreadyFetched(PathBuf),
+    Write {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Submissions {
+        path: PathBuf,
+        message: String,
+    },
+    SubmitRefused(String),
+    DayExists(PathBuf),
+    Spawn {
+        program: String,
+        source: io::Error,
+    },
+    Watch(notify::Error),
+    WatchStopped,
+}
+
+impl RunError {
+    pub fn parse(path: &Path, input: &str, error: ParseError) -> Self {
+        RunError::Parse {
+            path: path.to_path_buf(),
+            source_line: input.lines().nth(error.line - 1).unwrap_or("").to_string(),
+            error,
+        }
+    }
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::UnknownDay { year, day } => {
+                let available: Vec<_> = registry::days(*year).map(|d| d.day.to_string()).collect();
+                if available.is_empty() {
+                    write!(f, "no days are implemented for {}", year)
+                } else {
+                    write!(
+                        f,
+                        "day {} of {} is not implemented (available days: {})",
+                        day,
+                        year,
+                        available.join(", ")
+                    )
+                }
+            }
+            RunError::MissingInput { path, source } => {
+                write!(f, "could not read input `{}`: {}", path.display(), source)
+            }
+            RunError::Parse {
+                path,
+                source_line,
+                error,
+            } => {
+                let gutter = " ".repeat(error.line.to_string().len());
+                let highlight = error
+                    .text
+                    .lines()
+                    .next()
+                    .unwrap_or("")
+                    .chars()
+                    .count()
+                    .max(1);
+
+                writeln!(f, "could not parse `{}`: {}", path.display(), error)?;
+                writeln!(
+                    f,
+                    "{}--> {}:{}:{}",
+                    gutter,
+                    path.display(),
+                    error.line,
+                    error.column
+                )?;
+                writeln!(f, "{} |", gutter)?;
+                writeln!(f, "{} | {}", error.line, source_line)?;
+                write!(
+                    f,
+                    "{} | {}{}",
+                    gutter,
+                    " ".repeat(error.column - 1),
+                    "^".repeat(highlight)
+                )
+            }
+            RunError::History { path, message } => {
+                write!(f, "benchmark history `{}`: {}", path.display(), message)
+            }
+            RunError::Answers { path, message } => {
+                write!(f, "answers file `{}`: {}", path.display(), message)
+            }
+            RunError::VerificationFailed(count) => {
+                write!(f, "verification failed for {} part(s)", count)
+            }
+            RunError::Config { path, message } => {
+                write!(f, "config `{}`: {}", path.display(), message)
+            }
+            RunError::Client(err) => write!(f, "{}", err),
+            RunError::AlreadyFetched(path) => write!(
+                f,
+                "`{}` is already downloaded, inputs are never fetched twice",
+                path.display()
+            ),
+            RunError::Write { path, source } => {
+                write!(f, "could not write `{}`: {}", path.display(), source)
+            }
+            RunError::Submissions { path, message } => {
+                write!(f, "submission log `{}`: {}", path.display(), message)
+            }
+            RunError::SubmitRefused(reason) => write!(f, "not submitting: {}", reason),
+            RunError::DayExists(path) => write!(f, "`{}` already exists", path.display()),
+            RunError::Spawn { program, source } => {
+                write!(f, "could not run `{}`: {}", program, source)
+            }
+            RunError::Watch(err) => write!(f, "could not watch files: {}", err),
+            RunError::WatchStopped => write!(f, "the file watcher stopped"),
+        }
+    }
+}
+
+impl std::error::Error for RunError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RunError::UnknownDay { .. } => None,
+            RunError::MissingInput { source, .. } => Some(source),
+            RunError::Parse { error, .. } => Some(error),
+            RunError::Client(err) => Some(err),
+            RunError::Write { source, .. } => Some(source),
+            RunError::Spawn { source, .. } => Some(source),
+            RunError::Watch(err) => Some(err),
+            RunError::History { .. }
+            | RunError::Answers { .. }
+            | RunError::VerificationFailed(_)
+            | RunError::Config { .. }
+            | RunError::AlreadyFetched(_)
+            | RunError::Submissions { .. }
+            | RunError::SubmitRefused(_)
+            | RunError::DayExists(_)
+            | RunError::WatchStopped => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub input_hash: String,
+    pub parse: Stats,
+    pub prepare: Stats,
+    pub part_1: Option<(Answer, Stats)>,
+    pub part_2: Option<(Answer, Stats)>,
+    /// `Solution::explain` of each part that was run, when asked for
+    pub explanations: Vec<(u8, String)>,
+}
+
+impl DayReport {
+    /// Sum of the median time of every phase that was run.
+    pub fn total(&self) -> std::time::Duration {
+        [
+            Some(&self.parse),
+            Some(&self.prepare),
+            self.part_1.as_ref().map(|p| &p.1),
+            self.part_2.as_ref().map(|p| &p.1),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|stats| stats.median)
+        .sum()
+    }
+}
+
+pub fn default_input_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("input/{}/day{:02}.txt", year, day))
+}
+
+pub fn find_day(year: u16, day: u8) -> Result<&'static RegisteredDay, RunError> {
+    registry::find(year, day).ok_or(RunError::UnknownDay { year, day })
+}
+
+pub fn read_input(path: &Path) -> Result<String, RunError> {
+    fs::read_to_string(path).map_err(|source| RunError::MissingInput {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn solve(
+    registered: &RegisteredDay,
+    path: &Path,
+    input: &str,
+    part: Option<u8>,
+    runs: usize,
+    explain: bool,
+) -> Result<DayReport, RunError> {
+    let (solution, parse) = measure_repeated(runs, || registered.build(input));
+    let solution = solution.map_err(|err| RunError::parse(path, input, err))?;
+
+    // prepared work is cached, so every timed run after the first needs a
+    // fresh solution
+    let mut samples: Vec<_> = (1..runs)
+        .filter_map(|_| registered.build(input).ok())
+        .map(|fresh| measure(|| fresh.prepare()).1)
+        .collect();
+    samples.push(measure(|| solution.prepare()).1);
+    let prepare = Stats::from_samples(samples);
+
+    let part_1 = part
+        .is_none_or(|p| p == 1)
+        .then(|| measure_repeated(runs, || solution.part_1()));
+    let part_2 = part
+        .is_none_or(|p| p == 2)
+        .then(|| measure_repeated(runs, || solution.part_2()));
+
+    let explanations = [1, 2]
+        .into_iter()
+        .filter(|&p| explain && part.is_none_or(|part| part == p))
+        .filter_map(|p| Some((p, solution.explain(p)?)))
+        .collect();
+
+    Ok(DayReport {
+        year: registered.year,
+        day: registered.day,
+        input_hash: output::input_hash(input),
+        parse,
+        prepare,
+        part_1,
+        part_2,
+        explanations,
+    })
+}
+
+pub fn run_day(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    runs: usize,
+    format: Format,
+    explain: bool,
+) -> Result<(), RunError> {
+    let result = find_day(year, day).and_then(|registered| {
+        let path = input.unwrap_or_else(|| default_input_path(year, day));
+        let input = read_input(&path)?;
+        solve(registered, &path, &input, part, runs, explain)
+    });
+
+    if format != Format::Text {
+        let entries = match &result {
+            Ok(report) => output::entries(report),
+            Err(err) => output::error_entries(year, day, part, err),
+        };
+        print!("{}", output::render(format, &entries));
+        return result.map(|_| ());
+    }
+
+    let report = result?;
+    println!("parse: {}", report.parse);
+    println!("prepare: {}", report.prepare);
+    if let Some((answer, stats)) = &report.part_1 {
+        println!("part 1: {} ({})", answer, stats);
+    }
+    if let Some((answer, stats)) = &report.part_2 {
+        println!("part 2: {} ({})", answer, stats);
+    }
+    println!("total: {}", format_duration(report.total()));
+
+    if explain && report.explanations.is_empty() {
+        println!("\nday {:02} has no explanation to offer", day);
+    }
+    for (part, explanation) in &report.explanations {
+        println!("\npart {} explained:\n{}", part, explanation);
+    }
+
+    Ok(())
+}
+
+pub fn list_days() {
+    for registered in registry::DAYS {
+        let path = default_input_path(registered.year, registered.day);
+        let status = if path.exists() { "" } else { " (missing)" };
+        println!(
+            "{} day {:02}: {}{}",
+            registered.year,
+            registered.day,
+            path.display(),
+            status
+        );
+    }
+}
+
+fn run_with_default_input(registered: &RegisteredDay, runs: usize) -> Result<DayReport, RunError> {
+    let path = default_input_path(registered.year, registered.day);
+    let input = read_input(&path)?;
+    solve(registered, &path, &input, None, runs, false)
+}
+
+pub fn run_all(year: u16, runs: usize, format: Format) {
+    // days may run concurrently, so use `--threads 1` for comparable timings
+    let days: Vec<_> = registry::days(year).collect();
+    let results: Vec<(u8, Result<DayReport, RunError>)> = parallel::map(&days, |registered| {
+        (registered.day, run_with_default_input(registered, runs))
+    });
+
+    if format != Format::Text {
+        let entries: Vec<_> = results
+            .iter()
+            .flat_map(|(day, result)| match result {
+                Ok(report) => output::entries(report),
+                Err(err) => output::error_entries(year, *day, None, err),
+            })
+            .collect();
+        print!("{}", output::render(format, &entries));
+        return;
+    }
+
+    let rows: Vec<[String; 8]> = results
+        .into_iter()
+        .map(|(day, result)| match result {
+            Ok(report) => {
+                let total = report.total();
+                let (p1, t1) = report.part_1.unwrap();
+                let (p2, t2) = report.part_2.unwrap();
+                [
+                    format!("{:02}", report.day),
+                    p1.to_string(),
+                    p2.to_string(),
+                    format_duration(report.parse.median),
+                    format_duration(report.prepare.median),
+                    format_duration(t1.median),
+                    format_duration(t2.median),
+                    format_duration(total),
+                ]
+            }
+            Err(err) => [
+                format!("{:02}", day),
+                err.to_string().lines().next().unwrap_or("").to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+            ],
+        })
+        .collect();
+
+    let header = [
+        "day",
+        "part 1",
+        "part 2",
+        "parse",
+        "prepare",
+        "part 1 time",
+        "part 2 time",
+        "total",
+    ]
+    .map(String::from);
+    print_table(&header, &rows);
+}
+
+fn print_table<const N: usize>(header: &[String; N], rows: &[[String; N]]) {
+    let widths: Vec<usize> = (0..N)
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].len())
+                .chain([header[col].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let format_row = |row: &[String; N]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!("{}", format_row(header));
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|&width| "-".repeat(width))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+    for row in rows {
+        println!("{}", format_row(row));
+    }
+}
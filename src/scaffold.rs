@@ -0,0 +1,122 @@
+use crate::runner::{RunError, default_input_path};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = "use crate::answer::Answer;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::test_solution;
+
+#[derive(Debug)]
+pub struct Day{NN} {}
+
+impl Solution for Day{NN} {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let _ = input;
+        Ok(Day{NN} {})
+    }
+
+    fn part_1(&self) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part_2(&self) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+test_solution!({N},
+    example: \"day{NN}_test.txt\" => {},
+);
+";
+
+pub fn source_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("src/y{}/day{:02}.rs", year, day))
+}
+
+fn example_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("input/{}/day{:02}_test.txt", year, day))
+}
+
+/// Creates `src/yYYYY/dayNN.rs` from the template plus empty input files. The
+/// build script registers the new module (and year) on the next build.
+/// Existing inputs are left untouched, but an existing source file is never
+/// overwritten.
+pub fn new_day(year: u16, day: u8, root: &Path) -> Result<Vec<PathBuf>, RunError> {
+    let source = root.join(source_path(year, day));
+    if source.exists() {
+        return Err(RunError::DayExists(source));
+    }
+
+    let code = TEMPLATE
+        .replace("{NN}", &format!("{:02}", day))
+        .replace("{N}", &day.to_string());
+    let mut created = vec![];
+    for (path, content) in [
+        (source, code.as_str()),
+        (root.join(default_input_path(year, day)), ""),
+        (root.join(example_path(year, day)), ""),
+    ] {
+        if path.exists() {
+            continue;
+        }
+        let write_error = |source| RunError::Write {
+            path: path.clone(),
+            source,
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(write_error)?;
+        }
+        fs::write(&path, content).map_err(write_error)?;
+        created.push(path);
+    }
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::temp_dir;
+
+    #[test]
+    fn generates_a_day_from_the_template() {
+        let root = temp_dir("scaffold_new");
+
+        let created = new_day(2026, 12, &root).unwrap();
+        assert_eq!(
+            created,
+            [
+                root.join("src/y2026/day12.rs"),
+                root.join("input/2026/day12.txt"),
+                root.join("input/2026/day12_test.txt"),
+            ]
+        );
+
+        let code = fs::read_to_string(root.join("src/y2026/day12.rs")).unwrap();
+        assert!(code.contains("pub struct Day12 {}"));
+        assert!(code.contains("impl Solution for Day12 {"));
+        assert!(code.contains("test_solution!(12,\n    example: \"day12_test.txt\" => {},\n);"));
+        assert_eq!(
+            fs::read_to_string(root.join("input/2026/day12.txt")).unwrap(),
+            ""
+        );
+    }
+
+    #[test]
+    fn keeps_existing_files() {
+        let root = temp_dir("scaffold_existing");
+        fs::create_dir_all(root.join("input/2025")).unwrap();
+        fs::write(root.join("input/2025/day03.txt"), "987654321\n").unwrap();
+
+        let created = new_day(2025, 3, &root).unwrap();
+        assert_eq!(created.len(), 2);
+        assert_eq!(
+            fs::read_to_string(root.join("input/2025/day03.txt")).unwrap(),
+            "987654321\n"
+        );
+
+        let again = new_day(2025, 3, &root);
+        assert!(matches!(again, Err(RunError::DayExists(_))));
+    }
+}
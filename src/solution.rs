@@ -1,6 +1,23 @@
+use crate::answer::Answer;
+use crate::parse::ParseError;
+
 pub trait Solution {
-    fn new(input: &str) -> Self;
+    fn new(input: &str) -> Result<Self, ParseError>
+    where
+        Self: Sized;
+
+    /// Work shared by both parts, timed as its own phase between `new` and
+    /// the parts. Solutions keep the result in a lazily filled cache (e.g. a
+    /// `OnceLock`) that the parts read, so it runs once whoever asks first.
+    fn prepare(&self) {}
+
+    fn part_1(&self) -> Answer;
+    fn part_2(&self) -> Answer;
 
-    fn part_1(&self) -> String;
-    fn part_2(&self) -> String;
+    /// A human-readable account of how a part reached its answer, for
+    /// auditing it on real inputs. Most solutions have nothing to add.
+    fn explain(&self, part: u8) -> Option<String> {
+        let _ = part;
+        None
+    }
 }
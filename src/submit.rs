@@ -0,0 +1,400 @@
+use crate::answer::Answer;
+use crate::client::Client;
+use crate::config::Config;
+use crate::runner::{RunError, default_input_path, find_day, read_input};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const SUBMISSIONS_PATH: &str = "submissions.json";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    AlreadySolved,
+    Wait { seconds: u64 },
+    Unknown { message: String },
+}
+
+impl SubmitOutcome {
+    fn is_rejection(&self) -> bool {
+        matches!(
+            self,
+            SubmitOutcome::TooHigh | SubmitOutcome::TooLow | SubmitOutcome::Wrong
+        )
+    }
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "right answer"),
+            SubmitOutcome::TooHigh => write!(f, "wrong answer, too high"),
+            SubmitOutcome::TooLow => write!(f, "wrong answer, too low"),
+            SubmitOutcome::Wrong => write!(f, "wrong answer"),
+            SubmitOutcome::AlreadySolved => write!(f, "this part is already solved"),
+            SubmitOutcome::Wait { seconds } => {
+                write!(f, "answered too recently, wait {}s", seconds)
+            }
+            SubmitOutcome::Unknown { message } => write!(f, "unrecognised response: {}", message),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmitOutcome,
+    pub timestamp: u64,
+}
+
+/// Every answer sent so far, plus when each puzzle accepts answers again.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SubmissionLog {
+    pub attempts: Vec<Attempt>,
+    pub cooldown_until: BTreeMap<String, u64>,
+}
+
+fn puzzle_key(year: u16, day: u8, part: u8) -> String {
+    format!("{}-{:02}-{}", year, day, part)
+}
+
+impl SubmissionLog {
+    pub fn load(path: &Path) -> Result<Self, RunError> {
+        if !path.exists() {
+            return Ok(SubmissionLog::default());
+        }
+        let content = fs::read_to_string(path).map_err(|err| log_error(path, err))?;
+        serde_json::from_str(&content).map_err(|err| log_error(path, err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), RunError> {
+        let content = serde_json::to_string_pretty(self).map_err(|err| log_error(path, err))?;
+        fs::write(path, content + "\n").map_err(|err| log_error(path, err))
+    }
+
+    fn attempts_for(&self, year: u16, day: u8, part: u8) -> Vec<&Attempt> {
+        self.attempts
+            .iter()
+            .filter(|a| a.year == year && a.day == day && a.part == part)
+            .collect()
+    }
+
+    /// Why `answer` must not be sent, judging from earlier attempts.
+    fn refusal(&self, year: u16, day: u8, part: u8, answer: &str, now: u64) -> Option<String> {
+        let attempts = self.attempts_for(year, day, part);
+
+        if let Some(solved) = attempts
+            .iter()
+            .find(|a| a.outcome == SubmitOutcome::Correct)
+        {
+            return Some(format!("already solved with {}", solved.answer));
+        }
+        if let Some(rejected) = attempts
+            .iter()
+            .find(|a| a.answer == answer && a.outcome.is_rejection())
+        {
+            return Some(format!(
+                "{} was already submitted ({})",
+                answer, rejected.outcome
+            ));
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome: SubmitOutcome| {
+                attempts
+                    .iter()
+                    .filter(move |a| a.outcome == outcome)
+                    .filter_map(|a| a.answer.parse::<i128>().ok())
+            };
+            if let Some(high) = bound(SubmitOutcome::TooHigh).filter(|&h| value >= h).min() {
+                return Some(format!("{} was too high, so {} is too", high, value));
+            }
+            if let Some(low) = bound(SubmitOutcome::TooLow).filter(|&l| value <= l).max() {
+                return Some(format!("{} was too low, so {} is too", low, value));
+            }
+        }
+        if let Some(&until) = self.cooldown_until.get(&puzzle_key(year, day, part))
+            && until > now
+        {
+            return Some(format!("still cooling down, wait {}s", until - now));
+        }
+        None
+    }
+}
+
+fn log_error(path: &Path, message: impl ToString) -> RunError {
+    RunError::Submissions {
+        path: path.to_path_buf(),
+        message: message.to_string(),
+    }
+}
+
+/// Reads the verdict and any cooldown (in seconds) out of the answer page.
+pub fn parse_response(html: &str) -> (SubmitOutcome, Option<u64>) {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>")
+        .unwrap()
+        .captures(html)
+        .map_or(html, |c| c.get(1).unwrap().as_str());
+    let text = Regex::new(r"<[^>]+>").unwrap().replace_all(article, "");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let cooldown = if let Some(c) = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait")
+        .unwrap()
+        .captures(&text)
+    {
+        let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = c[2].parse().unwrap();
+        Some(minutes * 60 + seconds)
+    } else if text.contains("wait one minute") {
+        Some(60)
+    } else {
+        Regex::new(r"wait (\d+) minutes")
+            .unwrap()
+            .captures(&text)
+            .map(|c| c[1].parse::<u64>().unwrap() * 60)
+    };
+
+    let outcome = if text.contains("That's the right answer") {
+        SubmitOutcome::Correct
+    } else if text.contains("You gave an answer too recently") {
+        SubmitOutcome::Wait {
+            seconds: cooldown.unwrap_or(60),
+        }
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            SubmitOutcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            SubmitOutcome::TooLow
+        } else {
+            SubmitOutcome::Wrong
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        SubmitOutcome::AlreadySolved
+    } else {
+        SubmitOutcome::Unknown { message: text }
+    };
+
+    (outcome, cooldown)
+}
+
+/// Sends `answer` unless the log shows it can't be right or the puzzle is
+/// still cooling down, and records the outcome.
+pub fn submit(
+    config: &Config,
+    log_path: &Path,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+    now: u64,
+) -> Result<SubmitOutcome, RunError> {
+    if !answer.is_solved() {
+        return Err(RunError::SubmitRefused(
+            "there is no answer to submit".to_string(),
+        ));
+    }
+    let answer = answer.to_string();
+    let year = config.year;
+
+    let mut log = SubmissionLog::load(log_path)?;
+    if let Some(reason) = log.refusal(year, day, part, &answer, now) {
+        return Err(RunError::SubmitRefused(reason));
+    }
+
+    let client = Client::new(config).map_err(RunError::Client)?;
+    let html = client
+        .submit_answer(year, day, part, &answer)
+        .map_err(RunError::Client)?;
+    let (outcome, cooldown) = parse_response(&html);
+
+    if let Some(seconds) = cooldown {
+        log.cooldown_until
+            .insert(puzzle_key(year, day, part), now + seconds);
+    }
+    if !matches!(outcome, SubmitOutcome::Wait { .. }) {
+        log.attempts.push(Attempt {
+            year,
+            day,
+            part,
+            answer,
+            outcome: outcome.clone(),
+            timestamp: now,
+        });
+    }
+    log.save(log_path)?;
+
+    Ok(outcome)
+}
+
+pub fn run_submit(config: &Config, day: u8, part: u8) -> Result<(), RunError> {
+    let registered = find_day(config.year, day)?;
+    let path = default_input_path(config.year, day);
+    let input = read_input(&path)?;
+    let solution = registered
+        .build(&input)
+        .map_err(|err| RunError::parse(&path, &input, err))?;
+    let answer = match part {
+        1 => solution.part_1(),
+        _ => solution.part_2(),
+    };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    println!(
+        "submitting {} for {} day {:02} part {}",
+        answer, config.year, day, part
+    );
+    let outcome = submit(config, Path::new(SUBMISSIONS_PATH), day, part, &answer, now)?;
+    println!("{}", outcome);
+
+    if outcome.is_rejection() {
+        Err(RunError::SubmitRefused(format!("{} was rejected", answer)))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{TestServer, config, temp_dir};
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><main>\n<article><p>{}</p></article>\n</main></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn parses_site_responses() {
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                SubmitOutcome::Correct,
+                None,
+            ),
+            (
+                "That's not the right answer; your answer is too high. Please wait one minute before trying again.",
+                SubmitOutcome::TooHigh,
+                Some(60),
+            ),
+            (
+                "That's not the right answer; your answer is too low. <a href=\"/2025/day/1\">[Return to Day 1]</a>",
+                SubmitOutcome::TooLow,
+                None,
+            ),
+            (
+                "That's not the right answer. please wait 5 minutes before trying again.",
+                SubmitOutcome::Wrong,
+                Some(300),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 23s left to wait.",
+                SubmitOutcome::Wait { seconds: 83 },
+                Some(83),
+            ),
+            (
+                "You don't seem to be solving the right level. Did you already complete it?",
+                SubmitOutcome::AlreadySolved,
+                None,
+            ),
+        ];
+
+        for (message, outcome, cooldown) in cases {
+            assert_eq!(
+                parse_response(&page(message)),
+                (outcome, cooldown),
+                "{}",
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn records_attempts_and_never_resends_wrong_answers() {
+        let server = TestServer::start(vec![
+            (
+                200,
+                page(
+                    "That's not the right answer; your answer is too high. Please wait one minute before trying again.",
+                ),
+            ),
+            (200, page("That's the right answer!")),
+        ]);
+        let log_path = temp_dir("submit_log").join("submissions.json");
+        let config = config(&server);
+
+        let outcome = submit(&config, &log_path, 5, 2, &Answer::from(500), 1_000).unwrap();
+        assert_eq!(outcome, SubmitOutcome::TooHigh);
+
+        // same answer, a bigger one, and one during the cooldown never reach the server
+        for (answer, now) in [(500, 2_000), (600, 2_000), (400, 1_030)] {
+            let result = submit(&config, &log_path, 5, 2, &Answer::from(answer), now);
+            assert!(
+                matches!(result, Err(RunError::SubmitRefused(_))),
+                "{}",
+                answer
+            );
+        }
+
+        let outcome = submit(&config, &log_path, 5, 2, &Answer::from(400), 1_061).unwrap();
+        assert_eq!(outcome, SubmitOutcome::Correct);
+        let solved = submit(&config, &log_path, 5, 2, &Answer::from(401), 2_000);
+        assert!(matches!(solved, Err(RunError::SubmitRefused(_))));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2025/day/5/answer");
+        assert_eq!(requests[0].body, "level=2&answer=500");
+        assert_eq!(requests[1].body, "level=2&answer=400");
+
+        let log = SubmissionLog::load(&log_path).unwrap();
+        assert_eq!(log.attempts.len(), 2);
+    }
+
+    #[test]
+    fn unrecognised_responses_can_be_retried() {
+        let server = TestServer::start(vec![
+            (200, page("Something unexpected happened.")),
+            (200, page("That's the right answer!")),
+        ]);
+        let log_path = temp_dir("submit_unknown").join("submissions.json");
+        let config = config(&server);
+
+        let outcome = submit(&config, &log_path, 3, 1, &Answer::from(7), 100).unwrap();
+        assert!(matches!(outcome, SubmitOutcome::Unknown { .. }));
+        let outcome = submit(&config, &log_path, 3, 1, &Answer::from(7), 200).unwrap();
+        assert_eq!(outcome, SubmitOutcome::Correct);
+
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn wait_responses_set_a_cooldown_without_recording_an_attempt() {
+        let server = TestServer::start(vec![(
+            200,
+            page("You gave an answer too recently. You have 45s left to wait."),
+        )]);
+        let log_path = temp_dir("submit_wait").join("submissions.json");
+
+        let outcome = submit(&config(&server), &log_path, 1, 1, &Answer::from(3), 100).unwrap();
+        assert_eq!(outcome, SubmitOutcome::Wait { seconds: 45 });
+
+        let log = SubmissionLog::load(&log_path).unwrap();
+        assert!(log.attempts.is_empty());
+        assert_eq!(log.cooldown_until["2025-01-1"], 145);
+    }
+}
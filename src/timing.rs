@@ -0,0 +1,101 @@
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// Summary of the durations of repeated runs of the same phase.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no timing samples");
+        samples.sort_unstable();
+
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Stats {
+            runs: samples.len(),
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.runs == 1 {
+            return write!(f, "{}", format_duration(self.median));
+        }
+        write!(
+            f,
+            "median {}, min {}, max {}, {} runs",
+            format_duration(self.median),
+            format_duration(self.min),
+            format_duration(self.max),
+            self.runs
+        )
+    }
+}
+
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Runs `f` `runs` times (at least once), keeping the last result.
+pub fn measure_repeated<T>(runs: usize, mut f: impl FnMut() -> T) -> (T, Stats) {
+    let (mut result, first) = measure(&mut f);
+    let mut samples = vec![first];
+
+    for _ in 1..runs {
+        let (next, elapsed) = measure(&mut f);
+        result = next;
+        samples.push(elapsed);
+    }
+
+    (result, Stats::from_samples(samples))
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..1_000 => format!("{} ns", nanos),
+        1_000..1_000_000 => format!("{:.1} µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.3} ms", nanos as f64 / 1e6),
+        _ => format!("{:.3} s", nanos as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn median_of_even_sample_count() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros).to_vec();
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2_500));
+        assert_eq!(stats.max, Duration::from_micros(4));
+    }
+
+    #[test]
+    fn picks_unit_by_magnitude() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3 µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.500 ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.500 s");
+    }
+}
@@ -0,0 +1,161 @@
+use crate::answer::Answer;
+use crate::registry;
+use crate::runner::{RunError, default_input_path, read_input};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct AcceptedAnswers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl AcceptedAnswers {
+    pub fn for_part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            _ => self.part_2.as_deref(),
+        }
+    }
+}
+
+/// Contents of `answers.toml`, keyed by year and then by `dayNN`.
+pub type AnswersFile = BTreeMap<String, BTreeMap<String, AcceptedAnswers>>;
+
+pub fn load_answers(path: &Path) -> Result<AnswersFile, RunError> {
+    let answers_error = |message: String| RunError::Answers {
+        path: path.to_path_buf(),
+        message,
+    };
+    let content = fs::read_to_string(path).map_err(|err| answers_error(err.to_string()))?;
+    toml::from_str(&content).map_err(|err| answers_error(err.to_string()))
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn check(answer: &Answer, accepted: Option<&str>) -> Self {
+        match accepted {
+            None => Verdict::Unknown,
+            Some(expected) if *answer == expected => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+pub fn verify_all(year: u16, answers_path: &Path) -> Result<(), RunError> {
+    let answers = load_answers(answers_path)?;
+    let none_accepted = AcceptedAnswers::default();
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    for registered in registry::days(year) {
+        let accepted = answers
+            .get(&year.to_string())
+            .and_then(|days| days.get(&format!("day{:02}", registered.day)))
+            .unwrap_or(&none_accepted);
+
+        let path = default_input_path(year, registered.day);
+        let solution = read_input(&path).and_then(|input| {
+            registered
+                .build(&input)
+                .map_err(|err| RunError::parse(&path, &input, err))
+        });
+        let solution = match solution {
+            Ok(solution) => solution,
+            Err(err) => {
+                let message = err.to_string();
+                println!(
+                    "day {:02}: ERROR {}",
+                    registered.day,
+                    message.lines().next().unwrap_or("")
+                );
+                failed += 2;
+                continue;
+            }
+        };
+
+        for part in [1, 2] {
+            let answer = match part {
+                1 => solution.part_1(),
+                _ => solution.part_2(),
+            };
+            let verdict = Verdict::check(&answer, accepted.for_part(part));
+
+            match &verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Unknown => unknown += 1,
+            }
+            match &verdict {
+                Verdict::Fail { expected } => println!(
+                    "day {:02} part {}: {} (expected {}, got {})",
+                    registered.day, part, verdict, expected, answer
+                ),
+                _ => println!(
+                    "day {:02} part {}: {} ({})",
+                    registered.day, part, verdict, answer
+                ),
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} unknown", passed, failed, unknown);
+
+    if failed > 0 {
+        Err(RunError::VerificationFailed(failed))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_answers_against_accepted_text() {
+        assert_eq!(
+            Verdict::check(&Answer::from(42u64), Some("42")),
+            Verdict::Pass
+        );
+        assert_eq!(
+            Verdict::check(&Answer::from(41), Some("42")),
+            Verdict::Fail {
+                expected: "42".to_string()
+            }
+        );
+        assert_eq!(Verdict::check(&Answer::from(42), None), Verdict::Unknown);
+        assert_eq!(
+            Verdict::check(&Answer::Unsolved, Some("unsolved")),
+            Verdict::Fail {
+                expected: "unsolved".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn repo_answers_file_parses() {
+        let answers = load_answers(Path::new("answers.toml")).unwrap();
+        assert_eq!(answers["2025"]["day01"].for_part(1), Some("1132"));
+    }
+}
@@ -0,0 +1,212 @@
+use crate::runner::RunError;
+use notify::{RecursiveMode, Watcher};
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::mpsc;
+use std::time::Duration;
+
+// editors often write a file in several steps, so wait for a quiet moment
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Default, PartialEq)]
+pub struct TestSummary {
+    pub passed: usize,
+    pub failed: Vec<String>,
+}
+
+/// Picks the `test <name> ... ok|FAILED` lines out of libtest's output.
+pub fn parse_test_output(stdout: &str) -> TestSummary {
+    let mut summary = TestSummary::default();
+    for line in stdout.lines() {
+        let Some((name, result)) = line
+            .strip_prefix("test ")
+            .and_then(|rest| rest.split_once(" ... "))
+        else {
+            continue;
+        };
+        match result.trim() {
+            "ok" => summary.passed += 1,
+            "FAILED" => summary.failed.push(name.to_string()),
+            _ => {}
+        }
+    }
+    summary
+}
+
+/// One line per part comparing this run's answer with the previous run's.
+pub fn answer_diff(previous: &BTreeMap<u8, String>, current: &BTreeMap<u8, String>) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, answer)| match previous.get(part) {
+            None => format!("part {}: {}", part, answer),
+            Some(before) if before == answer => format!("part {}: {} (unchanged)", part, answer),
+            Some(before) => format!("part {}: {} -> {}", part, before, answer),
+        })
+        .collect()
+}
+
+/// Whether a change to `path` should trigger a rerun of `day`.
+pub fn is_relevant(path: &Path, day: u8) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let module = format!("day{:02}", day);
+    name == format!("{}.rs", module) || (name.starts_with(&module) && name.ends_with(".txt"))
+}
+
+fn cargo(args: &[&str]) -> Result<Output, RunError> {
+    // `CARGO` is set when we were started through `cargo run`
+    let program = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    Command::new(&program)
+        .args(args)
+        .output()
+        .map_err(|source| RunError::Spawn { program, source })
+}
+
+fn rerun(year: u16, day: u8, previous: &mut BTreeMap<u8, String>) -> Result<(), RunError> {
+    let build = cargo(&["build", "--quiet", "--color", "never"])?;
+    if !build.status.success() {
+        print_failure("build failed", &build.stderr);
+        return Ok(());
+    }
+
+    let filter = format!("y{}::day{:02}::", year, day);
+    let tests = cargo(&["test", "--color", "never", "--", &filter])?;
+    let summary = parse_test_output(&String::from_utf8_lossy(&tests.stdout));
+    if !tests.status.success() && summary.passed == 0 && summary.failed.is_empty() {
+        print_failure("examples: test build failed", &tests.stderr);
+    } else if summary.failed.is_empty() {
+        println!("examples: {} passed", summary.passed);
+    } else {
+        println!(
+            "examples: {} passed, {} FAILED",
+            summary.passed,
+            summary.failed.len()
+        );
+        for name in &summary.failed {
+            println!("  {}", name);
+        }
+    }
+
+    let (year, day) = (year.to_string(), day.to_string());
+    let run = cargo(&[
+        "run", "--quiet", "--", "--year", &year, "run", "--day", &day, "--format", "json",
+    ])?;
+    let entries: Vec<serde_json::Value> = serde_json::from_slice(&run.stdout).unwrap_or_default();
+    let current: BTreeMap<u8, String> = entries
+        .iter()
+        .filter_map(|entry| {
+            let part = entry["part"].as_u64()? as u8;
+            let answer = match (&entry["answer"], &entry["error"]) {
+                (serde_json::Value::String(answer), _) => answer.clone(),
+                (_, serde_json::Value::String(error)) => {
+                    format!("error: {}", error.lines().next().unwrap_or(""))
+                }
+                _ => "unsolved".to_string(),
+            };
+            Some((part, answer))
+        })
+        .collect();
+    if current.is_empty() {
+        println!("real input: no result");
+    }
+    for line in answer_diff(previous, &current) {
+        println!("{}", line);
+    }
+    *previous = current;
+
+    Ok(())
+}
+
+/// Prints `headline` and the first compiler errors from `stderr`.
+fn print_failure(headline: &str, stderr: &[u8]) {
+    println!("{}", headline);
+    let stderr = String::from_utf8_lossy(stderr);
+    for line in stderr
+        .lines()
+        .filter(|line| line.starts_with("error"))
+        .take(10)
+    {
+        println!("  {}", line);
+    }
+}
+
+/// Reruns a day's example tests and real input whenever its source file or
+/// one of its inputs changes, until interrupted.
+pub fn watch(year: u16, day: u8) -> Result<(), RunError> {
+    let dirs = [
+        PathBuf::from(format!("src/y{}", year)),
+        PathBuf::from(format!("input/{}", year)),
+    ];
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(RunError::Watch)?;
+    for dir in &dirs {
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .map_err(RunError::Watch)?;
+    }
+
+    let relevant = |event: notify::Result<notify::Event>| {
+        event.is_ok_and(|event| {
+            !event.kind.is_access() && event.paths.iter().any(|path| is_relevant(path, day))
+        })
+    };
+
+    let mut previous = BTreeMap::new();
+    println!("watching {} day {:02}, press ctrl-c to stop", year, day);
+    loop {
+        println!("---");
+        rerun(year, day, &mut previous)?;
+
+        // block until something relevant changes, then let the burst settle
+        while !relevant(receiver.recv().map_err(|_| RunError::WatchStopped)?) {}
+        while receiver.recv_timeout(DEBOUNCE).is_ok() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_test_results() {
+        let stdout = "
+running 3 tests
+test y2025::day03::test_day03_example ... ok
+test y2025::day03::test_day03_edge ... FAILED
+test y2025::day03::test_day03_other ... ok
+
+failures:
+";
+        assert_eq!(
+            parse_test_output(stdout),
+            TestSummary {
+                passed: 2,
+                failed: vec!["y2025::day03::test_day03_edge".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn diffs_answers_against_the_previous_run() {
+        let previous = BTreeMap::from([(1, "356".to_string()), (2, "10".to_string())]);
+        let current = BTreeMap::from([(1, "357".to_string()), (2, "10".to_string())]);
+
+        assert_eq!(
+            answer_diff(&previous, &current),
+            ["part 1: 356 -> 357", "part 2: 10 (unchanged)"]
+        );
+        assert_eq!(answer_diff(&BTreeMap::new(), &current)[0], "part 1: 357");
+    }
+
+    #[test]
+    fn only_the_days_files_are_relevant() {
+        assert!(is_relevant(Path::new("src/y2025/day03.rs"), 3));
+        assert!(is_relevant(Path::new("input/2025/day03_test.txt"), 3));
+        assert!(is_relevant(Path::new("input/2025/day03.txt"), 3));
+        assert!(!is_relevant(Path::new("src/y2025/day13.rs"), 3));
+        assert!(!is_relevant(Path::new("src/y2025/.day03.rs.swp"), 3));
+    }
+}
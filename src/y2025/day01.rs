@@ -0,0 +1,305 @@
+use crate::answer::Answer;
+use crate::parse::{ParseError, parse_field};
+use crate::solution::Solution;
+use crate::test_solution;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Rotation {
+    direction: Direction,
+    distance: i32,
+}
+
+impl Rotation {
+    pub fn new(direction: Direction, distance: i32) -> Self {
+        Rotation {
+            direction,
+            distance,
+        }
+    }
+
+    /// How many clicks of this rotation land on `target` of a dial with
+    /// `size` positions when starting from `position`, without stepping
+    /// through the clicks one by one.
+    fn passes(&self, position: i32, target: i32, size: i32) -> i32 {
+        if self.distance <= 0 {
+            return 0;
+        }
+        // clicks needed to first reach the target
+        let first = match self.direction {
+            Direction::Right => (target - position).rem_euclid(size),
+            Direction::Left => (position - target).rem_euclid(size),
+        };
+        let first = if first == 0 { size } else { first };
+
+        if self.distance < first {
+            0
+        } else {
+            (self.distance - first) / size + 1
+        }
+    }
+
+    fn displacement(&self) -> i32 {
+        match self.direction {
+            Direction::Left => -self.distance,
+            Direction::Right => self.distance,
+        }
+    }
+}
+
+/// How often a target position was reached.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TargetHits {
+    /// rotations that ended on the target
+    pub at_end: usize,
+    /// clicks that landed on the target, including the last click of a rotation
+    pub during: usize,
+}
+
+#[derive(Debug)]
+pub struct DialRun {
+    pub hits: BTreeMap<i32, TargetHits>,
+    pub final_position: i32,
+    /// the starting position and the position after every rotation, if traced
+    pub trace: Option<Vec<i32>>,
+}
+
+/// A rotary dial with `size` positions numbered from 0, counting how often
+/// each target position is reached while applying rotations.
+#[derive(Debug, Clone)]
+pub struct Dial {
+    size: i32,
+    start: i32,
+    targets: BTreeSet<i32>,
+    trace: bool,
+}
+
+impl Dial {
+    pub fn new(size: i32, start: i32, targets: impl IntoIterator<Item = i32>) -> Self {
+        assert!(size > 0, "a dial needs at least one position");
+        Dial {
+            size,
+            start: start.rem_euclid(size),
+            targets: targets.into_iter().map(|t| t.rem_euclid(size)).collect(),
+            trace: false,
+        }
+    }
+
+    /// The safe's dial: 100 positions, starting at 50, watching 0.
+    pub fn safe() -> Self {
+        Dial::new(100, 50, [0])
+    }
+
+    /// Also record the position after every rotation.
+    pub fn traced(self) -> Self {
+        Dial {
+            trace: true,
+            ..self
+        }
+    }
+
+    pub fn run(&self, rotations: &[Rotation]) -> DialRun {
+        let mut hits: BTreeMap<i32, TargetHits> = self
+            .targets
+            .iter()
+            .map(|&target| (target, TargetHits::default()))
+            .collect();
+        let mut trace = self.trace.then(|| vec![self.start]);
+        let mut position = self.start;
+
+        for rotation in rotations {
+            for (&target, target_hits) in hits.iter_mut() {
+                target_hits.during += rotation.passes(position, target, self.size) as usize;
+            }
+            position = (position + rotation.displacement()).rem_euclid(self.size);
+            if let Some(target_hits) = hits.get_mut(&position) {
+                target_hits.at_end += 1;
+            }
+            if let Some(trace) = &mut trace {
+                trace.push(position);
+            }
+        }
+
+        DialRun {
+            hits,
+            final_position: position,
+            trace,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Day01 {
+    rotations: Vec<Rotation>,
+}
+
+impl Solution for Day01 {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let rotations = input
+            .lines()
+            .map(|line| {
+                let direction = match line.chars().next() {
+                    Some('L') => Direction::Left,
+                    Some('R') => Direction::Right,
+                    _ => {
+                        return Err(ParseError::at(
+                            input,
+                            line,
+                            "expected a rotation starting with `L` or `R`",
+                        ));
+                    }
+                };
+                let distance = parse_field(input, &line[1..], "distance")?;
+
+                Ok(Rotation::new(direction, distance))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day01 { rotations })
+    }
+
+    fn part_1(&self) -> Answer {
+        Dial::safe().run(&self.rotations).hits[&0].at_end.into()
+    }
+
+    fn part_2(&self) -> Answer {
+        Dial::safe().run(&self.rotations).hits[&0].during.into()
+    }
+
+    fn explain(&self, part: u8) -> Option<String> {
+        let run = Dial::safe().traced().run(&self.rotations);
+        let trace = run.trace?;
+        let mut lines: Vec<String> = self
+            .rotations
+            .iter()
+            .zip(trace.windows(2))
+            .map(|(rotation, step)| {
+                let letter = match rotation.direction {
+                    Direction::Left => 'L',
+                    Direction::Right => 'R',
+                };
+                format!(
+                    "{}{}: {} -> {}",
+                    letter, rotation.distance, step[0], step[1]
+                )
+            })
+            .collect();
+        let hits = run.hits[&0];
+        lines.push(match part {
+            1 => format!("{} rotations ended on 0", hits.at_end),
+            _ => format!("{} clicks landed on 0", hits.during),
+        });
+        lines.push(format!("the dial rests at {}", run.final_position));
+        Some(lines.join("\n"))
+    }
+}
+
+test_solution!(1,
+    example: "day01_test.txt" => { part_1: "3", part_2: "6" },
+    // rotations spanning more than one full turn, ending on 0
+    full_turns: "day01_edge_test.txt" => { part_1: "2", part_2: "5" },
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn rotations(input: &str) -> Vec<Rotation> {
+        Day01::new(input).unwrap().rotations
+    }
+
+    // the click-by-click simulation the closed form replaced
+    fn passes_by_clicks(rotation: &Rotation, mut position: i32, target: i32, size: i32) -> i32 {
+        let click_unit = match rotation.direction {
+            Direction::Left => -1,
+            Direction::Right => 1,
+        };
+        let mut hits = 0;
+        for _ in 0..rotation.distance {
+            position = (position + click_unit).rem_euclid(size);
+            if position == target {
+                hits += 1;
+            }
+        }
+        hits
+    }
+
+    fn rotation() -> impl Strategy<Value = Rotation> {
+        let direction = prop_oneof![Just(Direction::Left), Just(Direction::Right)];
+        (direction, -5..2_000).prop_map(|(direction, distance)| Rotation::new(direction, distance))
+    }
+
+    #[test]
+    fn counts_every_target_separately() {
+        let run = Dial::new(10, 0, [0, 5]).run(&rotations("R5\nR5\nL15\n"));
+
+        assert_eq!(
+            run.hits[&0],
+            TargetHits {
+                at_end: 1,
+                during: 2
+            }
+        );
+        assert_eq!(
+            run.hits[&5],
+            TargetHits {
+                at_end: 2,
+                during: 3
+            }
+        );
+        assert_eq!(run.final_position, 5);
+        assert!(run.trace.is_none());
+    }
+
+    #[test]
+    fn traces_positions_on_request() {
+        let run = Dial::new(7, 10, [1]).traced().run(&rotations("R4\nL2\n"));
+
+        assert_eq!(run.trace, Some(vec![3, 0, 5]));
+    }
+
+    #[test]
+    fn explains_every_rotation() {
+        let day = Day01::new("L68\nR48\n").unwrap();
+
+        assert_eq!(
+            day.explain(2).unwrap(),
+            "L68: 50 -> 82\nR48: 82 -> 30\n2 clicks landed on 0\nthe dial rests at 30"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn closed_form_matches_simulation(
+            rotation in rotation(),
+            size in 1..150,
+            position in 0..150,
+            target in 0..150,
+        ) {
+            let (position, target) = (position % size, target % size);
+            prop_assert_eq!(
+                rotation.passes(position, target, size),
+                passes_by_clicks(&rotation, position, target, size)
+            );
+        }
+
+        #[test]
+        fn part_2_matches_simulation(rotations in prop::collection::vec(rotation(), 0..50)) {
+            let mut position = 50;
+            let mut expected = 0;
+            for rotation in &rotations {
+                expected += passes_by_clicks(rotation, position, 0, 100);
+                position = (position + rotation.displacement()).rem_euclid(100);
+            }
+
+            prop_assert_eq!(Day01 { rotations }.part_2(), Answer::from(expected));
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use crate::answer::Answer;
+use crate::parallel;
+use crate::parse::{ParseError, parse_field};
+use crate::solution::Solution;
+use crate::test_solution;
+use std::collections::BTreeSet;
+
+#[derive(Debug)]
+pub struct ProductIdRange {
+    first_id: i64,
+    last_id: i64,
+}
+
+impl ProductIdRange {
+    pub fn new(first_id: i64, last_id: i64) -> Self {
+        ProductIdRange { first_id, last_id }
+    }
+}
+
+/// How many times a block may be repeated for an ID to match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepeatCounts {
+    Exactly(BTreeSet<u32>),
+    AtLeast(u32),
+}
+
+impl RepeatCounts {
+    fn allows(&self, count: u32) -> bool {
+        match self {
+            RepeatCounts::Exactly(counts) => counts.contains(&count),
+            RepeatCounts::AtLeast(min) => count >= *min,
+        }
+    }
+}
+
+/// IDs that, written in `radix`, are one block of digits repeated an allowed
+/// number of times (at least twice). An ID matches if any split into equal
+/// blocks fits, so `111111` is both `1` six times and `111` twice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepeatPattern {
+    radix: u32,
+    counts: RepeatCounts,
+}
+
+impl RepeatPattern {
+    pub fn new(radix: u32, counts: RepeatCounts) -> Self {
+        assert!((2..=36).contains(&radix), "radix must be between 2 and 36");
+        RepeatPattern { radix, counts }
+    }
+
+    /// A block repeated exactly one of `counts` times.
+    pub fn exactly(radix: u32, counts: impl IntoIterator<Item = u32>) -> Self {
+        RepeatPattern::new(radix, RepeatCounts::Exactly(counts.into_iter().collect()))
+    }
+
+    /// A block repeated `min` or more times.
+    pub fn at_least(radix: u32, min: u32) -> Self {
+        RepeatPattern::new(radix, RepeatCounts::AtLeast(min))
+    }
+
+    fn digit_count(&self, id: i64) -> u32 {
+        id.checked_ilog(self.radix as i64).map_or(1, |log| log + 1)
+    }
+
+    /// Block repeat counts worth trying for IDs of `length` digits.
+    fn counts_for(&self, length: u32) -> impl Iterator<Item = u32> + '_ {
+        (2..=length).filter(move |&c| length.is_multiple_of(c) && self.counts.allows(c))
+    }
+
+    pub fn matches(&self, id: i64) -> bool {
+        if id < 1 {
+            return false;
+        }
+        let radix = self.radix as i64;
+        let digits: Vec<i64> = std::iter::successors(Some(id), |&rest| Some(rest / radix))
+            .take_while(|&rest| rest > 0)
+            .map(|rest| rest % radix)
+            .collect();
+
+        self.counts_for(digits.len() as u32).any(|count| {
+            let block = &digits[..digits.len() / count as usize];
+            digits.chunks(block.len()).all(|chunk| chunk == block)
+        })
+    }
+
+    /// Every matching ID in the range, listed once. Only those IDs are
+    /// visited, not the whole range.
+    pub fn ids_in(&self, range: &ProductIdRange) -> BTreeSet<i64> {
+        let mut ids = BTreeSet::new();
+        let (first, last) = (range.first_id.max(1), range.last_id);
+        if first > last {
+            return ids;
+        }
+
+        let radix = self.radix as i128;
+        for length in self.digit_count(first)..=self.digit_count(last) {
+            for count in self.counts_for(length) {
+                let block_length = length / count;
+                // `block * multiplier` writes the block `count` times,
+                // e.g. 1001 for two blocks of three decimal digits
+                let multiplier = ((radix.pow(length) - 1) / (radix.pow(block_length) - 1)) as i64;
+                let lowest_block = radix.pow(block_length - 1) as i64;
+                let highest_block = (radix.pow(block_length) - 1) as i64;
+
+                let from = lowest_block.max((first as u64).div_ceil(multiplier as u64) as i64);
+                let to = highest_block.min(last / multiplier);
+                ids.extend((from..=to).map(|block| block * multiplier));
+            }
+        }
+
+        debug_assert!(ids.iter().all(|&id| self.matches(id)));
+        ids
+    }
+}
+
+#[derive(Debug)]
+pub struct Day02 {
+    id_ranges_to_verify: Vec<ProductIdRange>,
+}
+
+impl Day02 {
+    /// The ranges from the puzzle input, in input order.
+    pub fn id_ranges(&self) -> &[ProductIdRange] {
+        &self.id_ranges_to_verify
+    }
+
+    fn sum_matching_ids(&self, pattern: &RepeatPattern) -> Answer {
+        parallel::map(self.id_ranges(), |range| {
+            pattern.ids_in(range).into_iter().sum::<i64>()
+        })
+        .into_iter()
+        .sum::<i64>()
+        .into()
+    }
+}
+
+impl Solution for Day02 {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let id_ranges_to_verify = input
+            .trim_end()
+            .split(',')
+            .map(|range| {
+                let (first_id_str, last_id_str) = range
+                    .split_once('-')
+                    .ok_or_else(|| ParseError::at(input, range, "expected a `first-last` range"))?;
+                Ok(ProductIdRange::new(
+                    parse_field(input, first_id_str, "first id")?,
+                    parse_field(input, last_id_str, "last id")?,
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day02 {
+            id_ranges_to_verify,
+        })
+    }
+
+    fn part_1(&self) -> Answer {
+        self.sum_matching_ids(&RepeatPattern::exactly(10, [2]))
+    }
+
+    fn part_2(&self) -> Answer {
+        self.sum_matching_ids(&RepeatPattern::at_least(10, 2))
+    }
+}
+
+test_solution!(2, "1227775554", "4174379265");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn range(first_id: i64, last_id: i64) -> ProductIdRange {
+        ProductIdRange::new(first_id, last_id)
+    }
+
+    // the decimal string checks the generator replaced
+    fn is_doubled(id: i64) -> bool {
+        let id_str = id.to_string();
+        let mid = id_str.len() / 2;
+        id_str.len().is_multiple_of(2) && id_str[..mid] == id_str[mid..]
+    }
+
+    fn is_repeated(id: i64) -> bool {
+        let chars = id.to_string().chars().collect::<Vec<_>>();
+        (1..=chars.len() / 2)
+            .filter(|&block_size| chars.len().is_multiple_of(block_size))
+            .any(|block_size| {
+                let block = &chars[..block_size];
+                chars.chunks(block_size).all(|chunk| chunk == block)
+            })
+    }
+
+    #[test]
+    fn lists_ids_repeating_in_several_ways_once() {
+        let ids = RepeatPattern::at_least(10, 2).ids_in(&range(111_110, 111_112));
+        assert_eq!(ids, BTreeSet::from([111_111]));
+    }
+
+    #[test]
+    fn handles_the_widest_ids() {
+        // 19 digits only split into single repeated digits, up to 8888…
+        let ids =
+            RepeatPattern::at_least(10, 2).ids_in(&range(1_000_000_000_000_000_000, i64::MAX));
+        assert_eq!(
+            ids,
+            (1..=8)
+                .map(|digit| digit * 1_111_111_111_111_111_111)
+                .collect()
+        );
+
+        let ids = RepeatPattern::exactly(10, [2])
+            .ids_in(&range(999_999_999_999_999_990, 999_999_999_999_999_999));
+        assert_eq!(ids, BTreeSet::from([999_999_999_999_999_999]));
+
+        // 63 bits never split in two, so the largest doubled ID has 62
+        let ids = RepeatPattern::exactly(2, [2]).ids_in(&range((1 << 62) - 1, i64::MAX));
+        assert_eq!(ids, BTreeSet::from([(1 << 62) - 1]));
+    }
+
+    #[test]
+    fn checks_other_radixes() {
+        let hex_triples = RepeatPattern::exactly(16, [3]);
+        assert!(hex_triples.matches(0xabc_abc_abc));
+        assert!(hex_triples.matches(0x777));
+        assert!(!hex_triples.matches(0xabc_abc));
+        assert!(!hex_triples.matches(0xab_ab_ab_ab));
+
+        let ids = hex_triples.ids_in(&range(0x100, 0x1000));
+        assert_eq!(ids, (1..=15).map(|digit| digit * 0x111).collect());
+    }
+
+    proptest! {
+        #[test]
+        fn decimal_patterns_match_scanning_the_range(
+            first_id in 0i64..20_000_000,
+            width in 0i64..3_000,
+        ) {
+            let ids = first_id..=first_id + width;
+            let range = range(first_id, first_id + width);
+
+            prop_assert_eq!(
+                RepeatPattern::exactly(10, [2]).ids_in(&range),
+                ids.clone().filter(|&id| is_doubled(id)).collect::<BTreeSet<_>>()
+            );
+            prop_assert_eq!(
+                RepeatPattern::at_least(10, 2).ids_in(&range),
+                ids.filter(|&id| is_repeated(id)).collect::<BTreeSet<_>>()
+            );
+        }
+
+        #[test]
+        fn generator_agrees_with_checker(
+            radix in 2u32..=36,
+            counts in prop::collection::btree_set(2u32..8, 1..4),
+            first_id in 0i64..5_000_000,
+            width in 0i64..2_000,
+        ) {
+            let pattern = RepeatPattern::exactly(radix, counts);
+            let range = range(first_id, first_id + width);
+
+            prop_assert_eq!(
+                pattern.ids_in(&range),
+                (first_id..=first_id + width)
+                    .filter(|&id| pattern.matches(id))
+                    .collect::<BTreeSet<_>>()
+            );
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::answer::Answer;
+use crate::parallel;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::test_solution;
+
+#[derive(Debug)]
+pub struct Bank {
+    batteries: Vec<i64>,
+}
+
+/// The batteries turned on in a bank and the joltage they produce.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Selection {
+    pub indices: Vec<usize>,
+    pub joltage: i64,
+}
+
+#[derive(Debug)]
+pub struct Day03 {
+    banks: Vec<Bank>,
+}
+
+impl Solution for Day03 {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let banks = input
+            .lines()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(idx, char)| {
+                        char.to_digit(10).map(|digit| digit as i64).ok_or_else(|| {
+                            let fragment = &line[idx..idx + char.len_utf8()];
+                            ParseError::at(input, fragment, "expected a battery joltage digit")
+                        })
+                    })
+                    .collect::<Result<_, _>>()
+            })
+            .map(|batteries| batteries.map(|batteries| Bank { batteries }))
+            .collect::<Result<_, _>>()?;
+        Ok(Day03 { banks })
+    }
+
+    fn part_1(&self) -> Answer {
+        self.find_max_output_joltage(Self::battery_count(1))
+    }
+
+    fn part_2(&self) -> Answer {
+        self.find_max_output_joltage(Self::battery_count(2))
+    }
+
+    fn explain(&self, part: u8) -> Option<String> {
+        let battery_count = Self::battery_count(part);
+        let lines: Vec<String> = self
+            .banks()
+            .iter()
+            .map(|bank| bank.explain(bank.select_batteries(battery_count).as_ref()))
+            .collect();
+        Some(lines.join("\n"))
+    }
+}
+
+impl Day03 {
+    /// The banks from the puzzle input, in input order.
+    pub fn banks(&self) -> &[Bank] {
+        &self.banks
+    }
+
+    fn battery_count(part: u8) -> usize {
+        match part {
+            1 => 2,
+            _ => 12,
+        }
+    }
+
+    /// Unsolved if a bank holds fewer than `battery_count` batteries.
+    fn find_max_output_joltage(&self, battery_count: usize) -> Answer {
+        parallel::map(self.banks(), |bank| {
+            bank.select_batteries(battery_count)
+                .map(|selection| selection.joltage)
+        })
+        .into_iter()
+        .sum::<Option<i64>>()
+        .into()
+    }
+}
+
+impl Bank {
+    /// Greedily picks the largest digit that still leaves room for the
+    /// remaining batteries, preferring the leftmost on ties. `None` if the
+    /// bank holds fewer than `battery_count` batteries.
+    pub fn select_batteries(&self, battery_count: usize) -> Option<Selection> {
+        if self.batteries.len() < battery_count {
+            return None;
+        }
+        let mut indices = Vec::with_capacity(battery_count);
+        let mut joltage = 0;
+        let mut start_idx = 0;
+
+        for batteries_left in 0..battery_count {
+            let search_end = self.batteries.len() - (battery_count - 1 - batteries_left);
+            let (offset, &digit) = self.batteries[start_idx..search_end]
+                .iter()
+                .enumerate()
+                // max by the joltage then the smallest index
+                .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(&a.0)))
+                .unwrap();
+            indices.push(start_idx + offset);
+            joltage = joltage * 10 + digit;
+            start_idx += offset + 1;
+        }
+
+        Some(Selection { indices, joltage })
+    }
+
+    /// The bank and its joltage, with carets under the selected batteries.
+    fn explain(&self, selection: Option<&Selection>) -> String {
+        let digits: String = self.batteries.iter().map(|d| d.to_string()).collect();
+        let Some(selection) = selection else {
+            return format!("{} -> too few batteries", digits);
+        };
+        let mut carets = vec![' '; self.batteries.len()];
+        for &idx in &selection.indices {
+            carets[idx] = '^';
+        }
+        let carets: String = carets.into_iter().collect();
+        format!("{} -> {}\n{}", digits, selection.joltage, carets.trim_end())
+    }
+}
+
+test_solution!(3, "357", "3121910778619");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bank(digits: &str) -> Bank {
+        Day03::new(digits).unwrap().banks.remove(0)
+    }
+
+    #[test]
+    fn reports_the_selected_batteries() {
+        assert_eq!(
+            bank("811111111111119").select_batteries(2),
+            Some(Selection {
+                indices: vec![0, 14],
+                joltage: 89
+            })
+        );
+        assert_eq!(
+            bank("234234234234278").select_batteries(12),
+            Some(Selection {
+                indices: vec![2, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14],
+                joltage: 434234234278
+            })
+        );
+    }
+
+    #[test]
+    fn highlights_the_selected_digits() {
+        let bank = bank("818181911112111");
+        let explained = bank.explain(bank.select_batteries(2).as_ref());
+        assert_eq!(explained, "818181911112111 -> 92\n      ^    ^");
+    }
+
+    #[test]
+    fn leaves_part_2_unsolved_for_short_banks() {
+        let day = Day03::new("987654321111111\n12\n").unwrap();
+        assert_eq!(day.banks()[1].select_batteries(12), None);
+        assert_eq!(day.part_1(), "110");
+        assert_eq!(day.part_2(), Answer::Unsolved);
+        assert_eq!(
+            day.explain(2).unwrap().lines().last(),
+            Some("12 -> too few batteries")
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use crate::answer::Answer;
+use crate::geometry::Point2;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::test_solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    PaperRoll,
+}
+
+#[derive(Debug)]
+pub struct Day04 {
+    floor: Grid<Tile>,
+}
+
+impl Solution for Day04 {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let floor = Grid::parse(input, |ch| match ch {
+            '@' => Tile::PaperRoll,
+            _ => Tile::Empty,
+        })?;
+
+        Ok(Day04 { floor })
+    }
+
+    fn part_1(&self) -> Answer {
+        self.floor
+            .positions()
+            .filter(|&pos| Self::can_paper_roll_be_accessed(pos, &self.floor))
+            .count()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let mut floor = self.floor.clone();
+        let mut removed_count = 0;
+
+        let mut to_be_removed: Vec<_> = floor
+            .positions()
+            .filter(|&pos| Self::can_paper_roll_be_accessed(pos, &floor))
+            .collect();
+
+        while let Some(pos) = to_be_removed.pop() {
+            // a roll can be added twice
+            if floor[pos] == Tile::PaperRoll {
+                floor[pos] = Tile::Empty;
+                removed_count += 1;
+
+                for neighbor_pos in floor.neighbours_8(pos) {
+                    if Self::can_paper_roll_be_accessed(neighbor_pos, &floor) {
+                        to_be_removed.push(neighbor_pos);
+                    }
+                }
+            }
+        }
+
+        removed_count.into()
+    }
+}
+
+impl Day04 {
+    fn can_paper_roll_be_accessed(pos: Point2, floor: &Grid<Tile>) -> bool {
+        floor[pos] == Tile::PaperRoll
+            && floor
+                .neighbours_8(pos)
+                .filter(|&neighbor_pos| floor[neighbor_pos] == Tile::PaperRoll)
+                .count()
+                < 4
+    }
+}
+
+test_solution!(4, "13", "43");
@@ -1,3 +1,5 @@
+use crate::answer::Answer;
+use crate::parse::{ParseError, parse_field};
 use crate::solution::Solution;
 use crate::test_solution;
 use std::cmp::Ordering;
@@ -33,36 +35,41 @@ pub struct Day05 {
 }
 
 impl Solution for Day05 {
-    fn new(input: &str) -> Self {
-        let mut parts = input.split("\n\n");
-        let mut fresh_ingredient_ranges: Vec<_> = parts
-            .next()
-            .unwrap()
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let (ranges_section, ingredients_section) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::at_end(
+                input,
+                "expected a blank line between ranges and ingredients",
+            )
+        })?;
+
+        let mut fresh_ingredient_ranges: Vec<_> = ranges_section
             .lines()
             .map(|line| {
-                let mut parts = line.split("-");
-                let min = parts.next().unwrap().parse::<Ingredient>().unwrap();
-                let max = parts.next().unwrap().parse::<Ingredient>().unwrap();
-                Range { min, max }
+                let (min, max) = line
+                    .split_once('-')
+                    .ok_or_else(|| ParseError::at(input, line, "expected a `min-max` range"))?;
+                Ok(Range {
+                    min: parse_field(input, min, "range start")?,
+                    max: parse_field(input, max, "range end")?,
+                })
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
         fresh_ingredient_ranges.sort();
 
-        let mut ingredients: Vec<_> = parts
-            .next()
-            .unwrap()
+        let mut ingredients: Vec<_> = ingredients_section
             .lines()
-            .map(|line| line.parse::<Ingredient>().unwrap())
-            .collect();
+            .map(|line| parse_field::<Ingredient>(input, line, "ingredient"))
+            .collect::<Result<_, _>>()?;
         ingredients.sort();
 
-        Day05 {
+        Ok(Day05 {
             fresh_ingredient_ranges,
             ingredients,
-        }
+        })
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         let ranges = &self.fresh_ingredient_ranges;
 
         let mut fresh_count = 0;
@@ -82,19 +89,19 @@ impl Solution for Day05 {
             }
         }
 
-        fresh_count.to_string()
+        fresh_count.into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         let mut disjoint_ranges: Vec<Range> = Vec::new();
 
         for current_range in &self.fresh_ingredient_ranges {
             // checking last is enough due to sorting
-            if let Some(last_range) = disjoint_ranges.last_mut() {
-                if last_range.is_overlapping(&current_range) {
-                    last_range.merge(&current_range);
-                    continue;
-                }
+            if let Some(last_range) = disjoint_ranges.last_mut()
+                && last_range.is_overlapping(current_range)
+            {
+                last_range.merge(current_range);
+                continue;
             }
             disjoint_ranges.push(current_range.clone());
         }
@@ -103,7 +110,7 @@ impl Solution for Day05 {
             .iter()
             .map(|range| range.max - range.min + 1)
             .sum::<i64>()
-            .to_string()
+            .into()
     }
 }
 
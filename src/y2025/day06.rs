@@ -0,0 +1,161 @@
+use crate::answer::Answer;
+use crate::parse::{ParseError, parse_field};
+use crate::solution::Solution;
+use crate::test_solution;
+use std::fmt::{Debug, Formatter};
+use std::ops::Range;
+
+struct Problem {
+    // read along each row (part 1)
+    row_numbers: Vec<i64>,
+    // read down each column, right to left (part 2)
+    column_numbers: Vec<i64>,
+    op: Box<dyn Fn(i64, i64) -> i64>,
+}
+
+impl Debug for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Problem")
+            .field("row_numbers", &self.row_numbers)
+            .field("column_numbers", &self.column_numbers)
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+pub struct Day06 {
+    problems: Vec<Problem>,
+}
+
+impl Solution for Day06 {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Day06 {
+            problems: parse_problems(input)?,
+        })
+    }
+
+    fn part_1(&self) -> Answer {
+        self.problems
+            .iter()
+            .map(|p| p.get_solution(&p.row_numbers))
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        self.problems
+            .iter()
+            .map(|p| p.get_solution(&p.column_numbers))
+            .sum::<i64>()
+            .into()
+    }
+}
+
+impl Problem {
+    pub fn get_solution(&self, numbers: &[i64]) -> i64 {
+        numbers
+            .iter()
+            .skip(1)
+            .fold(numbers[0], |acc, num| (self.op)(acc, *num))
+    }
+}
+
+fn parse_operator(op: char) -> Option<Box<dyn Fn(i64, i64) -> i64>> {
+    match op {
+        '*' => Some(Box::new(|a, b| a * b)),
+        '+' => Some(Box::new(|a, b| a + b)),
+        _ => None,
+    }
+}
+
+/// The part of `line` under the columns in `span`, cut short for short lines.
+fn slice<'a>(line: &'a str, span: &Range<usize>) -> &'a str {
+    &line[span.start.min(line.len())..span.end.min(line.len())]
+}
+
+// Problems are blocks of columns separated by blank columns, so a single pass
+// finds them and reads their numbers both by row and by column.
+fn parse_problems(input: &str) -> Result<Vec<Problem>, ParseError> {
+    let mut lines: Vec<&str> = input.lines().collect();
+    let op_row = lines
+        .pop()
+        .ok_or_else(|| ParseError::at_end(input, "missing operator row"))?;
+    if let Some(line) = lines.iter().chain([&op_row]).find(|line| !line.is_ascii()) {
+        return Err(ParseError::at(input, line, "expected an ASCII worksheet"));
+    }
+
+    let width = lines
+        .iter()
+        .chain([&op_row])
+        .map(|l| l.len())
+        .max()
+        .unwrap_or(0);
+    let is_blank = |x: usize| {
+        lines.iter().chain([&op_row]).all(|line| {
+            line.as_bytes()
+                .get(x)
+                .is_none_or(|b| b.is_ascii_whitespace())
+        })
+    };
+
+    let mut spans = vec![];
+    let mut x = 0;
+    while x < width {
+        let start = x;
+        while x < width && !is_blank(x) {
+            x += 1;
+        }
+        if x > start {
+            spans.push(start..x);
+        }
+        x += 1;
+    }
+
+    spans
+        .iter()
+        .enumerate()
+        .map(|(idx, span)| {
+            let op_str = slice(op_row, span).trim();
+            let op = op_str
+                .chars()
+                .next()
+                .ok_or_else(|| ParseError::at(input, op_row, "missing operator for a problem"))?;
+            let op = parse_operator(op)
+                .ok_or_else(|| ParseError::at(input, op_str, "expected `+` or `*`"))?;
+
+            let row_numbers = lines
+                .iter()
+                .map(|line| match slice(line, span).trim() {
+                    "" => Err(ParseError::at(
+                        input,
+                        line,
+                        format!("missing number for problem {}", idx + 1),
+                    )),
+                    number_str => parse_field(input, number_str, "number"),
+                })
+                .collect::<Result<_, _>>()?;
+
+            let column_numbers = span
+                .clone()
+                .rev()
+                .filter_map(|x| {
+                    let number_str: String = lines
+                        .iter()
+                        .filter_map(|line| line.as_bytes().get(x))
+                        .filter(|b| b.is_ascii_digit())
+                        .map(|&b| b as char)
+                        .collect();
+                    number_str.parse().ok()
+                })
+                .collect();
+
+            Ok(Problem {
+                row_numbers,
+                column_numbers,
+                op,
+            })
+        })
+        .collect()
+}
+
+test_solution!(6, "4277556", "3263827");
@@ -0,0 +1,91 @@
+use crate::answer::Answer;
+use crate::geometry::{Direction, Point2};
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+use crate::test_solution;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+
+#[derive(Debug)]
+pub struct Day07 {
+    start_pos: Point2,
+    manifold: Grid<char>,
+}
+
+impl Day07 {
+    fn is_splitter(&self, pos: Point2) -> bool {
+        self.manifold.get(pos) == Some(&'^')
+    }
+
+    fn max_y(&self) -> i64 {
+        self.manifold.height() as i64
+    }
+}
+
+impl Solution for Day07 {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let manifold = Grid::parse(input, |ch| ch)?;
+
+        Ok(Day07 {
+            start_pos: manifold
+                .find_char('S')
+                .ok_or_else(|| ParseError::at_end(input, "missing start position `S`"))?,
+            manifold,
+        })
+    }
+
+    fn part_1(&self) -> Answer {
+        fn go(curr_pos: Point2, visited: &mut HashSet<Point2>, ctx: &Day07) -> usize {
+            if curr_pos.y == ctx.max_y() || visited.contains(&curr_pos) {
+                return 0;
+            }
+
+            visited.insert(curr_pos);
+
+            let next_pos = curr_pos.step(Direction::Down);
+
+            if ctx.is_splitter(next_pos) {
+                let left_pos = next_pos.step(Direction::Left);
+                let right_pos = next_pos.step(Direction::Right);
+
+                1 + go(left_pos, visited, ctx) + go(right_pos, visited, ctx)
+            } else {
+                go(next_pos, visited, ctx)
+            }
+        }
+
+        let mut visited = HashSet::new();
+        go(self.start_pos, &mut visited, self).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        fn go(curr_pos: Point2, cache: &mut HashMap<Point2, u64>, ctx: &Day07) -> u64 {
+            if curr_pos.y == ctx.max_y() {
+                return 1;
+            }
+            if let Some(&count) = cache.get(&curr_pos) {
+                return count;
+            }
+
+            let next_pos = curr_pos.step(Direction::Down);
+
+            let total = if ctx.is_splitter(next_pos) {
+                let left_pos = next_pos.step(Direction::Left);
+                let right_pos = next_pos.step(Direction::Right);
+
+                go(left_pos, cache, ctx) + go(right_pos, cache, ctx)
+            } else {
+                go(next_pos, cache, ctx)
+            };
+
+            cache.insert(curr_pos, total);
+            total
+        }
+
+        let mut memo = HashMap::new();
+        go(self.start_pos, &mut memo, self).into()
+    }
+}
+
+test_solution!(7, "21", "40");
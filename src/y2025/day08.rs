@@ -1,21 +1,20 @@
+use crate::answer::Answer;
+use crate::geometry::Point3;
+use crate::parse::{ParseError, parse_field};
 use crate::solution::Solution;
 use crate::test_solution;
 use std::collections::HashMap;
+use std::sync::OnceLock;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct JunctionBox {
     id: usize,
-    x: i32,
-    y: i32,
-    z: i32,
+    position: Point3,
 }
 
 impl JunctionBox {
     fn distance_to(&self, other: &JunctionBox) -> f64 {
-        let dx = (self.x - other.x) as f64;
-        let dy = (self.y - other.y) as f64;
-        let dz = (self.z - other.z) as f64;
-        (dx * dx + dy * dy + dz * dz).sqrt()
+        self.position.euclidean(other.position)
     }
 }
 
@@ -78,9 +77,14 @@ impl CircuitManager {
 #[derive(Debug)]
 pub struct Day08 {
     junction_boxes: Vec<JunctionBox>,
+    sorted_pairs: OnceLock<Vec<(usize, usize)>>,
 }
 
 impl Day08 {
+    fn sorted_pairs(&self) -> &[(usize, usize)] {
+        self.sorted_pairs.get_or_init(|| self.get_sorted_pairs())
+    }
+
     fn get_sorted_pairs(&self) -> Vec<(usize, usize)> {
         let mut pairs = Vec::new();
 
@@ -105,27 +109,42 @@ impl Day08 {
 }
 
 impl Solution for Day08 {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, ParseError> {
         let junction_boxes = input
             .lines()
             .enumerate()
             .map(|(id, line)| {
-                let nums: Vec<i32> = line.split(',').map(|n| n.trim().parse().unwrap()).collect();
-                JunctionBox {
+                let nums: Vec<i64> = line
+                    .split(',')
+                    .map(|n| parse_field(input, n.trim(), "coordinate"))
+                    .collect::<Result<_, _>>()?;
+                let [x, y, z] = nums[..] else {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        "expected three coordinates `x,y,z`",
+                    ));
+                };
+                Ok(JunctionBox {
                     id,
-                    x: nums[0],
-                    y: nums[1],
-                    z: nums[2],
-                }
+                    position: Point3::new(x, y, z),
+                })
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day08 {
+            junction_boxes,
+            sorted_pairs: OnceLock::new(),
+        })
+    }
 
-        Day08 { junction_boxes }
+    fn prepare(&self) {
+        self.sorted_pairs();
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         let mut circuits = CircuitManager::new(self.junction_boxes.clone());
-        let pairs = self.get_sorted_pairs();
+        let pairs = self.sorted_pairs();
 
         for (id_a, id_b) in pairs.iter().take(1000) {
             circuits.connect(*id_a, *id_b);
@@ -134,24 +153,24 @@ impl Solution for Day08 {
         let mut sizes = circuits.get_circuit_sizes();
         sizes.sort_unstable_by(|a, b| b.cmp(a));
 
-        sizes.iter().take(3).product::<usize>().to_string()
+        sizes.iter().take(3).product::<usize>().into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         let mut circuits = CircuitManager::new(self.junction_boxes.clone());
-        let pairs = self.get_sorted_pairs();
+        let pairs = self.sorted_pairs();
 
-        for (id_a, id_b) in pairs {
+        for &(id_a, id_b) in pairs {
             if circuits.connect(id_a, id_b) && circuits.circuit_count() == 1 {
                 let box_a = &self.junction_boxes[id_a];
                 let box_b = &self.junction_boxes[id_b];
 
-                let result = box_a.x * box_b.x;
-                return result.to_string();
+                let result = box_a.position.x * box_b.position.x;
+                return result.into();
             }
         }
 
-        panic!("Shouldn't happen!");
+        Answer::Unsolved
     }
 }
 
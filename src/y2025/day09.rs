@@ -1,17 +1,19 @@
-use crate::day04::Position;
+use crate::answer::Answer;
+use crate::geometry::Point2;
+use crate::parse::{ParseError, parse_field};
 use crate::solution::Solution;
 use crate::test_solution;
 
 #[derive(Debug, Copy, Clone)]
 struct Rectangle {
-    min_x: i32,
-    max_x: i32,
-    min_y: i32,
-    max_y: i32,
+    min_x: i64,
+    max_x: i64,
+    min_y: i64,
+    max_y: i64,
 }
 
 impl Rectangle {
-    fn from_corners(p1: Position, p2: Position) -> Self {
+    fn from_corners(p1: Point2, p2: Point2) -> Self {
         Self {
             min_x: p1.x.min(p2.x),
             max_x: p1.x.max(p2.x),
@@ -21,16 +23,16 @@ impl Rectangle {
     }
 
     fn area(&self) -> i64 {
-        let width = (self.max_x - self.min_x).abs() as i64 + 1;
-        let height = (self.max_y - self.min_y).abs() as i64 + 1;
+        let width = (self.max_x - self.min_x).abs() + 1;
+        let height = (self.max_y - self.min_y).abs() + 1;
         width * height
     }
 
-    fn contains_point(&self, p: Position) -> bool {
+    fn contains_point(&self, p: Point2) -> bool {
         p.x > self.min_x && p.x < self.max_x && p.y > self.min_y && p.y < self.max_y
     }
 
-    fn is_sliced_by_vertical_edge(&self, start: Position, end: Position) -> bool {
+    fn is_sliced_by_vertical_edge(&self, start: Point2, end: Point2) -> bool {
         start.x == end.x
             && start.x > self.min_x
             && start.x < self.max_x
@@ -38,7 +40,7 @@ impl Rectangle {
             && start.y.max(end.y) >= self.max_y
     }
 
-    fn is_sliced_by_horizontal_edge(&self, start: Position, end: Position) -> bool {
+    fn is_sliced_by_horizontal_edge(&self, start: Point2, end: Point2) -> bool {
         start.y == end.y
             && start.y > self.min_y
             && start.y < self.max_y
@@ -49,7 +51,7 @@ impl Rectangle {
 
 #[derive(Debug)]
 pub struct Day09 {
-    tiles: Vec<Position>,
+    tiles: Vec<Point2>,
 }
 
 impl Day09 {
@@ -69,21 +71,23 @@ impl Day09 {
 }
 
 impl Solution for Day09 {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, ParseError> {
         let tiles = input
             .lines()
             .map(|line| {
-                let (x, y) = line.split_once(',').unwrap();
-                Position {
-                    x: x.trim().parse().unwrap(),
-                    y: y.trim().parse().unwrap(),
-                }
+                let (x, y) = line
+                    .split_once(',')
+                    .ok_or_else(|| ParseError::at(input, line, "expected a tile `x,y`"))?;
+                Ok(Point2 {
+                    x: parse_field(input, x.trim(), "x coordinate")?,
+                    y: parse_field(input, y.trim(), "y coordinate")?,
+                })
             })
-            .collect();
-        Day09 { tiles }
+            .collect::<Result<_, _>>()?;
+        Ok(Day09 { tiles })
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         let mut max_area = 0;
         for (i, &p1) in self.tiles.iter().enumerate() {
             for &p2 in &self.tiles[i + 1..] {
@@ -91,10 +95,10 @@ impl Solution for Day09 {
                 max_area = max_area.max(rect.area());
             }
         }
-        max_area.to_string()
+        max_area.into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         let mut max_area = 0;
 
         for i in 0..self.tiles.len() {
@@ -111,7 +115,7 @@ impl Solution for Day09 {
                 max_area = candidate.area();
             }
         }
-        max_area.to_string()
+        max_area.into()
     }
 }
 
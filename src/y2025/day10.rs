@@ -1,3 +1,6 @@
+use crate::answer::Answer;
+use crate::parallel;
+use crate::parse::{ParseError, parse_field};
 use crate::solution::Solution;
 use crate::test_solution;
 use good_lp::{Solution as LPSolution, SolverModel, coin_cbc, variable, variables};
@@ -46,32 +49,47 @@ pub struct Machine {
 }
 
 impl Machine {
-    pub fn from_str(s: &str) -> Self {
+    /// Parses the machine described by `line`, a line of `input`.
+    pub fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
         let regex = Regex::new(r"\[([#.]+)]\s(.+)\s\{([\d,]+)}").unwrap();
-        let captures = regex.captures(s).unwrap();
-
-        let light_diagram = captures[1].chars().map(Light::from_char).collect();
-
-        let joltage_requirements = captures[3]
+        let captures = regex.captures(line).ok_or_else(|| {
+            ParseError::at(
+                input,
+                line,
+                "expected a machine `[lights] (buttons)... {joltages}`",
+            )
+        })?;
+        let (lights, buttons, joltages) = (
+            captures.get(1).unwrap().as_str(),
+            captures.get(2).unwrap().as_str(),
+            captures.get(3).unwrap().as_str(),
+        );
+
+        let light_diagram = lights.chars().map(Light::from_char).collect();
+
+        let joltage_requirements = joltages
             .split(',')
-            .map(|jolt_str| jolt_str.parse().unwrap())
-            .collect();
+            .map(|jolt_str| parse_field(input, jolt_str, "joltage requirement"))
+            .collect::<Result<_, _>>()?;
 
         let tuple_regex = Regex::new(r"\((\d+(?:,\d+)*)\)").unwrap();
         let button_schematics = tuple_regex
-            .captures_iter(&captures[2])
+            .captures_iter(buttons)
             .map(|c| {
-                c[1].split(',')
-                    .map(|btn_schematic_str| btn_schematic_str.parse().unwrap())
+                c.get(1)
+                    .unwrap()
+                    .as_str()
+                    .split(',')
+                    .map(|btn_schematic_str| parse_field(input, btn_schematic_str, "light index"))
                     .collect()
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        Machine {
+        Ok(Machine {
             light_diagram,
             button_schematics,
             joltage_requirements,
-        }
+        })
     }
 
     pub(crate) fn min_presses_for_lights(&self) -> u64 {
@@ -148,25 +166,26 @@ pub struct Day10 {
 }
 
 impl Solution for Day10 {
-    fn new(input: &str) -> Self {
-        let machines = input.lines().map(Machine::from_str).collect();
-        Day10 { machines }
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let machines = input
+            .lines()
+            .map(|line| Machine::parse(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Day10 { machines })
     }
 
-    fn part_1(&self) -> String {
-        self.machines
-            .iter()
-            .map(|m| m.min_presses_for_lights())
+    fn part_1(&self) -> Answer {
+        parallel::map(&self.machines, |m| m.min_presses_for_lights())
+            .into_iter()
             .sum::<u64>()
-            .to_string()
+            .into()
     }
 
-    fn part_2(&self) -> String {
-        self.machines
-            .iter()
-            .map(|m| m.min_presses_for_joltage())
+    fn part_2(&self) -> Answer {
+        parallel::map(&self.machines, |m| m.min_presses_for_joltage())
+            .into_iter()
             .sum::<u64>()
-            .to_string()
+            .into()
     }
 }
 
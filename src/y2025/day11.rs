@@ -1,3 +1,5 @@
+use crate::answer::Answer;
+use crate::parse::ParseError;
 use crate::solution::Solution;
 use crate::test_solution;
 use std::collections::{BTreeMap, HashMap};
@@ -50,39 +52,38 @@ impl Day11 {
 }
 
 impl Solution for Day11 {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, ParseError> {
         let devices = input
             .lines()
             .map(|line| {
-                let mut parts = line.split(": ");
-                let device = parts.next().unwrap().to_string();
-                let connections = parts
-                    .next()
-                    .unwrap()
-                    .split(" ")
-                    .map(|s| s.to_string())
-                    .collect();
-                (device, connections)
+                let (device, connections) = line.split_once(": ").ok_or_else(|| {
+                    ParseError::at(input, line, "expected a device `name: outputs...`")
+                })?;
+                let connections = connections.split(" ").map(|s| s.to_string()).collect();
+                Ok((device.to_string(), connections))
             })
-            .collect();
-        Day11 { devices }
+            .collect::<Result<_, _>>()?;
+        Ok(Day11 { devices })
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         let mut memo = HashMap::new();
         let requirements = BTreeMap::new();
 
         self.count_paths_satisfying_requirements("you", "out", requirements, &mut memo)
-            .to_string()
+            .into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         let mut memo = HashMap::new();
         let requirements = BTreeMap::from([("dac", false), ("fft", false)]);
 
         self.count_paths_satisfying_requirements("svr", "out", requirements, &mut memo)
-            .to_string()
+            .into()
     }
 }
 
-test_solution!(11, "5", "2");
+test_solution!(11,
+    example: "day11_test.txt" => { part_1: "5" },
+    part_2_example: "day11_part2_test.txt" => { part_2: "2" },
+);